@@ -0,0 +1,4 @@
+mod declared;
+
+#[test]
+fn in_lib() {}
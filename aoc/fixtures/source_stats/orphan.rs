@@ -0,0 +1,6 @@
+// left behind, no module declares it
+#[test]
+fn in_orphan() {}
+
+#[test]
+fn also_in_orphan() {}
@@ -0,0 +1,71 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Result};
+
+/// Expected answers for a puzzle input, stored next to it as lines of "part N: answer"
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers(BTreeMap<u8, String>);
+
+impl Answers {
+    pub fn get(&self, part: u8) -> Option<&str> {
+        self.0.get(&part).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, part: u8, answer: &str) {
+        self.0.insert(part, answer.to_string());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl FromStr for Answers {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut answers = Answers::default();
+        for line in s.lines().filter(|line| !line.trim().is_empty()) {
+            let (part, answer) = line
+                .strip_prefix("part ")
+                .and_then(|line| line.split_once(": "))
+                .with_context(|| format!("Expected \"part N: answer\", found {line:?}"))?;
+            let part = part
+                .parse::<u8>()
+                .with_context(|| format!("Invalid part number {part:?}"))?;
+            if answers.get(part).is_some() {
+                bail!("More than one answer for part {part}");
+            }
+            answers.insert(part, answer);
+        }
+
+        Ok(answers)
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (part, answer) in &self.0 {
+            writeln!(f, "part {part}: {answer}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let answers = "part 1: 13140\npart 2: CMZ\n".parse::<Answers>().unwrap();
+
+        assert_eq!(answers.get(1), Some("13140"));
+        assert_eq!(answers.get(2), Some("CMZ"));
+        assert_eq!(answers.to_string(), "part 1: 13140\npart 2: CMZ\n");
+        assert!("part one: 1".parse::<Answers>().is_err());
+        assert!("part 1: 1\npart 1: 2".parse::<Answers>().is_err());
+    }
+}
@@ -0,0 +1,258 @@
+use anyhow::{Context, Result};
+
+/// A piece of a day's work that can run on its own thread: one part, or both parts when the
+/// day computes them together.
+pub struct Job {
+    pub parts: &'static [u8],
+    /// Returns one answer per entry in `parts`
+    pub solve: fn(&str) -> Result<Vec<String>>,
+}
+
+pub struct Day {
+    pub number: u32,
+    /// Directory of the day's crate, relative to the workspace root
+    pub dir: &'static str,
+    /// Puzzle input file, relative to `dir`
+    pub input: &'static str,
+    pub jobs: &'static [Job],
+}
+
+pub const DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        dir: "day1-calorie-counting",
+        input: "1input.txt",
+        jobs: &[Job {
+            parts: &[1, 2],
+            solve: |input| {
+                let inventory = input.parse::<day1_calorie_counting::Inventory>()?;
+                Ok(vec![
+                    inventory.top_n_total(1)?.to_string(),
+                    inventory.top_n_total(3)?.to_string(),
+                ])
+            },
+        }],
+    },
+    Day {
+        number: 2,
+        dir: "day2-rock-paper-scissors",
+        input: "input1.txt",
+        jobs: &[
+            Job {
+                parts: &[1],
+                solve: |input| {
+                    let rules = day2_rock_paper_scissors::Rules::classic();
+                    Ok(vec![day2_rock_paper_scissors::part_1(input, &rules)?.to_string()])
+                },
+            },
+            Job {
+                parts: &[2],
+                solve: |input| {
+                    let rules = day2_rock_paper_scissors::Rules::classic();
+                    Ok(vec![day2_rock_paper_scissors::part_2(input, &rules)?.to_string()])
+                },
+            },
+        ],
+    },
+    Day {
+        number: 3,
+        dir: "day3-rucksack-reorganization",
+        input: "input.txt",
+        jobs: &[
+            Job {
+                parts: &[1],
+                solve: |input| {
+                    let alphabet = day3_rucksack_reorganization::Alphabet::default();
+                    let rucksacks =
+                        day3_rucksack_reorganization::parse_rucksacks(input, &alphabet, 2)?;
+                    Ok(vec![day3_rucksack_reorganization::part_1(&rucksacks, &alphabet)?.to_string()])
+                },
+            },
+            Job {
+                parts: &[2],
+                solve: |input| {
+                    let alphabet = day3_rucksack_reorganization::Alphabet::default();
+                    let rucksacks =
+                        day3_rucksack_reorganization::parse_rucksacks(input, &alphabet, 2)?;
+                    let badges = day3_rucksack_reorganization::part_2(&rucksacks, 3, &alphabet)?;
+                    Ok(vec![badges.to_string()])
+                },
+            },
+        ],
+    },
+    Day {
+        number: 4,
+        dir: "day4-camp-cleanup",
+        input: "input.txt",
+        jobs: &[Job {
+            parts: &[1, 2],
+            solve: |input| {
+                let (part1, part2) = day4_camp_cleanup::solve(input)?;
+                Ok(vec![part1.to_string(), part2.to_string()])
+            },
+        }],
+    },
+    Day {
+        number: 5,
+        dir: "day5-supply-stacks",
+        input: "input.txt",
+        jobs: &[Job {
+            parts: &[1, 2],
+            solve: |input| {
+                let (stacks, procedure) = day5_supply_stacks::parse_input(input)?;
+                let (part1, part2) =
+                    day5_supply_stacks::crates_on_top_of_each_stack(stacks, &procedure)?;
+                Ok(vec![part1, part2])
+            },
+        }],
+    },
+    Day {
+        number: 6,
+        dir: "day6-tuning-trouble",
+        input: "input.txt",
+        jobs: &[
+            Job {
+                parts: &[1],
+                solve: |input| {
+                    Ok(vec![day6_tuning_trouble::chars_to_end_of_marker(input.to_string(), 4).to_string()])
+                },
+            },
+            Job {
+                parts: &[2],
+                solve: |input| {
+                    Ok(vec![day6_tuning_trouble::chars_to_end_of_marker(input.to_string(), 14).to_string()])
+                },
+            },
+        ],
+    },
+    Day {
+        number: 7,
+        dir: "day7-no-space-left-on-device",
+        input: "input.txt",
+        jobs: &[Job {
+            parts: &[1, 2],
+            solve: |input| {
+                let (part1, part2) = day7_no_space_left_on_device::solve(input)?;
+                Ok(vec![part1.to_string(), part2.to_string()])
+            },
+        }],
+    },
+    Day {
+        number: 8,
+        dir: "day8-treetop-tree-house",
+        input: "input.txt",
+        jobs: &[Job {
+            parts: &[1, 2],
+            solve: |input| {
+                let (part1, part2) = day8_treetop_tree_house::solve(input.to_string());
+                Ok(vec![part1.to_string(), part2.to_string()])
+            },
+        }],
+    },
+    Day {
+        number: 9,
+        dir: "day9-rope-bridge",
+        input: "input.txt",
+        jobs: &[
+            Job {
+                parts: &[1],
+                solve: |input| {
+                    let part1 = day9_rope_bridge::solve_part1(input).context("Invalid motion")?;
+                    Ok(vec![part1.to_string()])
+                },
+            },
+            Job {
+                parts: &[2],
+                solve: |input| {
+                    let part2 = day9_rope_bridge::solve_part2(input).context("Invalid motion")?;
+                    Ok(vec![part2.to_string()])
+                },
+            },
+        ],
+    },
+    Day {
+        number: 10,
+        dir: "day10-cathode-ray-tube",
+        input: "input.txt",
+        jobs: &[
+            Job {
+                parts: &[1],
+                solve: |input| Ok(vec![day10_cathode_ray_tube::solve_part1(input).to_string()]),
+            },
+            Job {
+                parts: &[2],
+                solve: |input| Ok(vec![day10_cathode_ray_tube::solve_part2(input)]),
+            },
+        ],
+    },
+    Day {
+        number: 11,
+        dir: "day11-monkey-in-the-middle",
+        input: "input.txt",
+        jobs: &[Job {
+            parts: &[1, 2],
+            solve: |input| {
+                let (part1, part2) = day11_monkey_in_the_middle::solve(input)?;
+                Ok(vec![part1.to_string(), part2.to_string()])
+            },
+        }],
+    },
+    Day {
+        number: 12,
+        dir: "day12-hill-climbing-algorithm",
+        input: "input.txt",
+        jobs: &[
+            Job {
+                parts: &[1],
+                solve: |input| Ok(vec![day12_hill_climbing_algorithm::solve(input, false).to_string()]),
+            },
+            Job {
+                parts: &[2],
+                solve: |input| Ok(vec![day12_hill_climbing_algorithm::solve(input, true).to_string()]),
+            },
+        ],
+    },
+    Day {
+        number: 13,
+        dir: "day13-distress-signal",
+        input: "input.txt",
+        jobs: &[
+            Job {
+                parts: &[1],
+                solve: |input| Ok(vec![day13_distress_signal::solve(input)?.to_string()]),
+            },
+            Job {
+                parts: &[2],
+                solve: |input| Ok(vec![day13_distress_signal::solve2(input)?.to_string()]),
+            },
+        ],
+    },
+    Day {
+        number: 14,
+        dir: "day14-regolith-reservoir",
+        input: "input.txt",
+        jobs: &[
+            Job {
+                parts: &[1],
+                solve: |input| Ok(vec![day14_regolith_reservoir::solve(input)?.to_string()]),
+            },
+            Job {
+                parts: &[2],
+                solve: |input| Ok(vec![day14_regolith_reservoir::solve2(input)?.to_string()]),
+            },
+        ],
+    },
+    Day {
+        number: 16,
+        dir: "day16-proboscidea-volcanium",
+        input: "input.txt",
+        jobs: &[Job {
+            parts: &[1],
+            solve: |input| Ok(vec![day16_proboscidea_volcanium::solve(input)?.to_string()]),
+        }],
+    },
+];
+
+pub fn find(number: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
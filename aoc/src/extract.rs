@@ -0,0 +1,137 @@
+use anyhow::{bail, Result};
+
+use crate::answers::Answers;
+
+const ARTICLE_START: &str = "<article class=\"day-desc\">";
+const ARTICLE_END: &str = "</article>";
+
+/// The examples and their expected answers, taken from a saved puzzle page
+#[derive(Debug, PartialEq, Eq)]
+pub struct Puzzle {
+    /// Every `<pre><code>` block of the description in page order, without repeats
+    pub examples: Vec<String>,
+    /// The last emphasized `<code><em>` value in each part's description, which is where the
+    /// puzzle states the answer for its example
+    pub answers: Answers,
+}
+
+pub fn extract(html: &str) -> Result<Puzzle> {
+    let articles = between_all(html, ARTICLE_START, ARTICLE_END);
+    if articles.is_empty() {
+        bail!("No puzzle description found, expected {ARTICLE_START}");
+    }
+
+    let mut examples = Vec::new();
+    let mut answers = Answers::default();
+    for (article, part) in articles.iter().zip(1u8..) {
+        for block in between_all(article, "<pre><code>", "</code></pre>") {
+            let example = text(block);
+            if !examples.contains(&example) {
+                examples.push(example);
+            }
+        }
+
+        if let Some(answer) = between_all(article, "<code><em>", "</em></code>").last() {
+            answers.insert(part, &text(answer));
+        }
+    }
+
+    Ok(Puzzle { examples, answers })
+}
+
+/// Every slice of `html` that sits between `start` and the next `end` after it
+fn between_all<'a>(html: &'a str, start: &str, end: &str) -> Vec<&'a str> {
+    let mut found = Vec::new();
+    let mut rest = html;
+    while let Some((_, after_start)) = rest.split_once(start) {
+        let Some((inner, after_end)) = after_start.split_once(end) else {
+            break;
+        };
+        found.push(inner);
+        rest = after_end;
+    }
+    found
+}
+
+/// The text content of an HTML fragment, with tags dropped and entities decoded
+fn text(html: &str) -> String {
+    let mut text = String::new();
+    let mut rest = html;
+    while let Some(idx) = rest.find(['<', '&']) {
+        text.push_str(&rest[..idx]);
+        rest = &rest[idx..];
+        if rest.starts_with('<') {
+            rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+        } else {
+            let (decoded, len) = decode_entity(rest);
+            text.push(decoded);
+            rest = &rest[len..];
+        }
+    }
+    text.push_str(rest);
+    text
+}
+
+/// Decodes the entity at the start of `s`, returning it with the number of bytes it took up.
+/// Anything unrecognised is kept as a literal '&'.
+fn decode_entity(s: &str) -> (char, usize) {
+    if let Some(end) = s.find(';').filter(|&end| end <= 10) {
+        let name = &s[1..end];
+        let decoded = match name {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => name
+                .strip_prefix("#x")
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .or_else(|| name.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                .and_then(char::from_u32),
+        };
+        if let Some(decoded) = decoded {
+            return (decoded, end + 1);
+        }
+    }
+    ('&', 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_single_example() {
+        let html = include_str!("../fixtures/regolith.html");
+
+        let puzzle = extract(html).unwrap();
+        assert_eq!(
+            puzzle.examples,
+            vec!["498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9\n"]
+        );
+        assert_eq!(puzzle.answers.to_string(), "part 1: 24\npart 2: 93\n");
+    }
+
+    #[test]
+    fn test_extract_multiple_examples() {
+        let html = include_str!("../fixtures/rope.html");
+
+        let puzzle = extract(html).unwrap();
+        assert_eq!(puzzle.examples.len(), 3);
+        assert_eq!(puzzle.examples[0], "R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2\n");
+        assert_eq!(puzzle.examples[1], "..##\n.H.T\n#s..\n");
+        assert_eq!(puzzle.examples[2], "R 5\nU 8\nL 8\nD 3\nR 17\nD 10\nL 25\nU 20\n");
+        assert_eq!(puzzle.answers.get(1), Some("13"));
+        assert_eq!(puzzle.answers.get(2), Some("36"));
+    }
+
+    #[test]
+    fn test_extract_errors() {
+        assert!(extract("<html><body><pre><code>1</code></pre></body></html>").is_err());
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!(text("<em>[[1],4]</em> &lt;= &#x5b;&#91; &amp;amp &nope;"), "[[1],4] <= [[ &amp &nope;");
+    }
+}
@@ -0,0 +1,156 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::thread;
+
+use anyhow::{bail, Context, Result};
+use common::progress;
+
+mod answers;
+mod days;
+mod extract;
+mod pool;
+mod report;
+mod run;
+
+const USAGE: &str = "usage:
+    aoc run <all|DAY> [--threads N]
+    aoc report
+    aoc extract <PUZZLE.html> <DAY DIR> [--example N] [--force]";
+
+fn main() -> Result<()> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args = args.iter().map(String::as_str).collect::<Vec<&str>>();
+
+    match args[..] {
+        ["run", which, ref options @ ..] => run(which, options),
+        ["report"] => report(),
+        ["extract", page, dir, ref options @ ..] => extract(page, Path::new(dir), options),
+        _ => bail!(USAGE),
+    }
+}
+
+fn run(which: &str, options: &[&str]) -> Result<()> {
+    let days = match which {
+        "all" => days::DAYS.iter().collect::<Vec<_>>(),
+        day => {
+            let number = day.parse::<u32>().context(USAGE)?;
+            vec![days::find(number).with_context(|| format!("No solution for day {number}"))?]
+        }
+    };
+
+    let threads = match options {
+        [] => thread::available_parallelism().map_or(1, |threads| threads.get()),
+        ["--threads", threads] => threads.parse::<usize>().context(USAGE)?,
+        _ => bail!(USAGE),
+    };
+    // progress lines from days running side by side would draw over each other
+    progress::set_enabled(threads <= 1);
+
+    let root = workspace_root()?;
+
+    let mut failures = 0;
+    run::run_days(&days, root, threads, |result| {
+        for (i, part) in result.job.parts.iter().enumerate() {
+            let day = result.day.number;
+            match &result.answers {
+                Ok(answers) if answers[i].contains('\n') => {
+                    println!("Day {day} part {part}:\n{}", answers[i].trim_end())
+                }
+                Ok(answers) => println!("Day {day} part {part}: {}", answers[i]),
+                Err(e) => {
+                    println!("Day {day} part {part}: error: {e:#}");
+                    failures += 1;
+                }
+            }
+        }
+    });
+
+    if failures > 0 {
+        bail!("{failures} part(s) failed");
+    }
+    Ok(())
+}
+
+/// Prints a Markdown table summarising every day: whether each part is solved and matches
+/// its recorded answer, how long it takes, and how much code and how many tests it has.
+fn report() -> Result<()> {
+    let root = workspace_root()?;
+
+    // one thread at a time so days don't skew each other's runtime
+    let mut results = Vec::new();
+    run::run_days(&days::DAYS.iter().collect::<Vec<_>>(), root, 1, |result| results.push(result));
+
+    let reports = days::DAYS
+        .iter()
+        .map(|day| {
+            let day_results = results
+                .extract_if(.., |result| result.day.number == day.number)
+                .collect::<Vec<_>>();
+            report::day_report(day, &day_results, root)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    print!("{}", report::markdown(&reports));
+    Ok(())
+}
+
+fn workspace_root() -> Result<&'static Path> {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .context("Runner should live inside the workspace")
+}
+
+/// Writes an example from a saved puzzle page to `example.txt` in the day's directory, along
+/// with the answers the puzzle gives for it in `example_answers.txt`. Files already there are
+/// only replaced with `--force`.
+fn extract(page: &str, dir: &Path, mut options: &[&str]) -> Result<()> {
+    let mut example_idx = 0;
+    let mut force = false;
+    while !options.is_empty() {
+        options = match options {
+            ["--example", idx, rest @ ..] => {
+                example_idx = idx.parse::<usize>().context(USAGE)?;
+                rest
+            }
+            ["--force", rest @ ..] => {
+                force = true;
+                rest
+            }
+            _ => bail!(USAGE),
+        };
+    }
+
+    let html = fs::read_to_string(page).with_context(|| format!("Could not read {page}"))?;
+    let puzzle = extract::extract(&html)?;
+    for (i, example) in puzzle.examples.iter().enumerate() {
+        let first_line = example.lines().next().unwrap_or_default();
+        println!("example {i}: {first_line} ({} lines)", example.lines().count());
+    }
+
+    let example = puzzle
+        .examples
+        .get(example_idx)
+        .with_context(|| format!("No example {example_idx}, found {}", puzzle.examples.len()))?;
+    let example_path = dir.join("example.txt");
+    let answers_path = dir.join("example_answers.txt");
+    if !force {
+        let answers_path = (!puzzle.answers.is_empty()).then_some(&answers_path);
+        for path in [Some(&example_path), answers_path].into_iter().flatten() {
+            if path.exists() {
+                bail!("{} already exists, pass --force to overwrite it", path.display());
+            }
+        }
+    }
+    fs::write(&example_path, example)
+        .with_context(|| format!("Could not write {}", example_path.display()))?;
+    println!("wrote example {example_idx} to {}", example_path.display());
+
+    if !puzzle.answers.is_empty() {
+        fs::write(&answers_path, puzzle.answers.to_string())
+            .with_context(|| format!("Could not write {}", answers_path.display()))?;
+        print!("wrote to {}:\n{}", answers_path.display(), puzzle.answers);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use std::collections::BTreeMap;
+use std::sync::mpsc;
+use std::sync::Mutex;
+use std::thread;
+
+/// Runs `work` over every task on `threads` std threads. Results are handed to `on_result` in
+/// the order the tasks were given, each as soon as it and every earlier result is ready.
+pub fn run_ordered<T, R>(
+    tasks: Vec<T>,
+    threads: usize,
+    work: impl Fn(T) -> R + Sync,
+    mut on_result: impl FnMut(R),
+) where
+    T: Send,
+    R: Send,
+{
+    let queue = Mutex::new(tasks.into_iter().enumerate());
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..threads.max(1) {
+            let sender = sender.clone();
+            let (queue, work) = (&queue, &work);
+            scope.spawn(move || loop {
+                let next_task = queue.lock().unwrap().next();
+                let Some((idx, task)) = next_task else {
+                    break;
+                };
+                if sender.send((idx, work(task))).is_err() {
+                    break;
+                }
+            });
+        }
+        // only the workers hold senders now, so the receiver ends once they are all done
+        drop(sender);
+
+        let mut finished = BTreeMap::new();
+        let mut next_idx = 0;
+        for (idx, result) in receiver {
+            finished.insert(idx, result);
+            while let Some(result) = finished.remove(&next_idx) {
+                on_result(result);
+                next_idx += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_run_ordered() {
+        // later tasks finish first, but results still come out in task order
+        let tasks = (0..8u64).collect::<Vec<u64>>();
+        let mut results = Vec::new();
+        run_ordered(
+            tasks,
+            4,
+            |task| {
+                thread::sleep(Duration::from_millis(10 * (8 - task)));
+                task * task
+            },
+            |result| results.push(result),
+        );
+
+        assert_eq!(results, vec![0, 1, 4, 9, 16, 25, 36, 49]);
+    }
+
+    #[test]
+    fn test_run_ordered_single_thread() {
+        let mut results = Vec::new();
+        run_ordered(vec!["a", "b", "c"], 0, str::to_uppercase, |result| results.push(result));
+
+        assert_eq!(results, vec!["A", "B", "C"]);
+    }
+}
@@ -0,0 +1,280 @@
+use std::fmt::Write;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+
+use crate::answers::Answers;
+use crate::days::Day;
+use crate::run::JobResult;
+
+const PARTS: [u8; 2] = [1, 2];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PartStatus {
+    /// Matches the recorded answer
+    Pass,
+    Fail { expected: String, actual: String },
+    /// Solved, but there is no recorded answer to check it against
+    Unchecked,
+    /// The answer is drawn rather than given as a value, e.g. day 10's CRT
+    Visual,
+    Missing,
+    Error(String),
+}
+
+impl PartStatus {
+    fn solved(&self) -> bool {
+        matches!(self, PartStatus::Pass | PartStatus::Unchecked)
+    }
+}
+
+#[derive(Debug)]
+pub struct DayReport {
+    pub number: u32,
+    /// Status of each of `PARTS`, in order
+    pub parts: Vec<PartStatus>,
+    pub runtime: Duration,
+    pub lines_of_code: usize,
+    pub tests: usize,
+}
+
+/// Builds the report for one day from the results of running every one of its jobs.
+pub fn day_report(day: &Day, results: &[JobResult], root: &Path) -> Result<DayReport> {
+    let day_dir = root.join(day.dir);
+    let answers_path = day_dir.join("answers.txt");
+    let recorded = match fs::read_to_string(&answers_path) {
+        Ok(recorded) => recorded
+            .parse::<Answers>()
+            .with_context(|| format!("Invalid answers in {}", answers_path.display()))?,
+        Err(_) => Answers::default(),
+    };
+
+    let parts = PARTS
+        .iter()
+        .map(|&part| {
+            let Some((result, idx)) = results.iter().find_map(|result| {
+                let idx = result.job.parts.iter().position(|&p| p == part)?;
+                Some((result, idx))
+            }) else {
+                return PartStatus::Missing;
+            };
+
+            match &result.answers {
+                Err(e) => PartStatus::Error(format!("{e:#}")),
+                Ok(answers) if answers[idx].contains('\n') => PartStatus::Visual,
+                Ok(answers) => match recorded.get(part) {
+                    Some(expected) if expected == answers[idx] => PartStatus::Pass,
+                    Some(expected) => PartStatus::Fail {
+                        expected: expected.to_string(),
+                        actual: answers[idx].clone(),
+                    },
+                    None => PartStatus::Unchecked,
+                },
+            }
+        })
+        .collect();
+
+    let (lines_of_code, tests) = source_stats_in(&day_dir.join("src"))?;
+
+    Ok(DayReport {
+        number: day.number,
+        parts,
+        runtime: results.iter().map(|result| result.elapsed).sum(),
+        lines_of_code,
+        tests,
+    })
+}
+
+/// Totals `source_stats` over the files compiled into the crate in `src`, following `mod`
+/// declarations from `lib.rs` and `main.rs`. Files no module points to aren't counted.
+fn source_stats_in(src: &Path) -> Result<(usize, usize)> {
+    let mut seen = HashSet::new();
+    let mut totals = (0, 0);
+    for root in ["lib.rs", "main.rs"] {
+        let path = src.join(root);
+        if path.is_file() {
+            module_stats(&path, &mut seen, &mut totals)?;
+        }
+    }
+    Ok(totals)
+}
+
+/// Adds the stats of the module at `path` and every module it declares to `totals`
+fn module_stats(
+    path: &Path,
+    seen: &mut HashSet<PathBuf>,
+    totals: &mut (usize, usize),
+) -> Result<()> {
+    if !seen.insert(path.to_path_buf()) {
+        return Ok(());
+    }
+    let source =
+        fs::read_to_string(path).with_context(|| format!("Could not read {}", path.display()))?;
+    let (lines_of_code, tests) = source_stats(&source);
+    totals.0 += lines_of_code;
+    totals.1 += tests;
+
+    // submodules of lib.rs, main.rs and mod.rs sit next to them, others in a directory of their
+    // own name
+    let parent = path.parent().unwrap_or(Path::new("."));
+    let dir = match path.file_stem().and_then(|stem| stem.to_str()) {
+        Some("lib" | "main" | "mod") | None => parent.to_path_buf(),
+        Some(stem) => parent.join(stem),
+    };
+    for name in declared_modules(&source) {
+        let file = dir.join(format!("{name}.rs"));
+        let path = if file.is_file() { file } else { dir.join(name).join("mod.rs") };
+        module_stats(&path, seen, totals)?;
+    }
+    Ok(())
+}
+
+/// The names of the modules declared in their own file, like `pub mod stats;`
+fn declared_modules(source: &str) -> Vec<&str> {
+    source
+        .lines()
+        .filter_map(|line| {
+            let tokens = line.split_whitespace().collect::<Vec<&str>>();
+            let [ref visibility @ .., "mod", name] = tokens[..] else {
+                return None;
+            };
+            let name = name.strip_suffix(';')?;
+            visibility.iter().all(|token| token.starts_with("pub")).then_some(name)
+        })
+        .collect()
+}
+
+/// Counts the lines of code, skipping blank and comment only lines, and the number of tests.
+fn source_stats(source: &str) -> (usize, usize) {
+    let lines = source.lines().map(str::trim);
+    let lines_of_code = lines
+        .clone()
+        .filter(|line| !line.is_empty() && !line.starts_with("//"))
+        .count();
+    let tests = lines.filter(|&line| line == "#[test]").count();
+
+    (lines_of_code, tests)
+}
+
+/// Renders the reports as a Markdown table, followed by a summary of the whole year.
+pub fn markdown(reports: &[DayReport]) -> String {
+    let mut md = String::new();
+    md.push_str("| Day | Part 1 | Part 2 | Runtime | Lines of code | Tests |\n");
+    md.push_str("|----:|--------|--------|--------:|--------------:|------:|\n");
+    for report in reports {
+        let _ = writeln!(
+            md,
+            "| {} | {} | {} | {:.2?} | {} | {} |",
+            report.number,
+            status_cell(&report.parts[0]),
+            status_cell(&report.parts[1]),
+            report.runtime,
+            report.lines_of_code,
+            report.tests
+        );
+    }
+
+    let statuses = reports.iter().flat_map(|report| &report.parts);
+    let both_solved = reports
+        .iter()
+        .filter(|report| report.parts.iter().all(PartStatus::solved))
+        .count();
+    let passed = statuses.clone().filter(|&status| *status == PartStatus::Pass).count();
+    let checked = statuses
+        .filter(|status| matches!(status, PartStatus::Pass | PartStatus::Fail { .. }))
+        .count();
+    let _ = writeln!(
+        md,
+        "\n{both_solved} of {} days have both parts solved, {passed} of {checked} recorded answers match, {:.2?} total runtime.",
+        reports.len(),
+        reports.iter().map(|report| report.runtime).sum::<Duration>()
+    );
+
+    md
+}
+
+fn status_cell(status: &PartStatus) -> String {
+    match status {
+        PartStatus::Pass => "pass".to_string(),
+        PartStatus::Fail { expected, actual } => format!("**fail**: {actual}, expected {expected}"),
+        PartStatus::Unchecked => "unchecked".to_string(),
+        PartStatus::Visual => "visual".to_string(),
+        PartStatus::Missing => "missing".to_string(),
+        PartStatus::Error(e) => format!("**error**: {}", e.replace('|', "\\|")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source_stats() {
+        let source = "use std::env;\n\n// a comment\nfn main() {}\n\n#[cfg(test)]\nmod tests {\n    #[test]\n    fn a() {}\n\n    #[test]\n    fn b() {}\n}\n";
+
+        assert_eq!(source_stats(source), (9, 2));
+    }
+
+    #[test]
+    fn test_declared_modules() {
+        let source = "pub mod audit;\nmod items;\npub(crate) mod plan;\nmod tests {\n// mod old;\n";
+
+        assert_eq!(declared_modules(source), vec!["audit", "items", "plan"]);
+    }
+
+    #[test]
+    fn test_only_compiled_files_count() {
+        // lib.rs declares one module, orphan.rs is left over and has tests of its own
+        let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/source_stats");
+
+        assert_eq!(source_stats_in(&src).unwrap(), (5, 2));
+    }
+
+    #[test]
+    fn test_markdown() {
+        let reports = [
+            DayReport {
+                number: 10,
+                parts: vec![PartStatus::Pass, PartStatus::Visual],
+                runtime: Duration::from_micros(1500),
+                lines_of_code: 120,
+                tests: 1,
+            },
+            DayReport {
+                number: 16,
+                parts: vec![
+                    PartStatus::Fail {
+                        expected: "1651".to_string(),
+                        actual: "1650".to_string(),
+                    },
+                    PartStatus::Missing,
+                ],
+                runtime: Duration::from_millis(80),
+                lines_of_code: 150,
+                tests: 2,
+            },
+            DayReport {
+                number: 12,
+                parts: vec![PartStatus::Pass, PartStatus::Unchecked],
+                runtime: Duration::from_millis(100),
+                lines_of_code: 90,
+                tests: 2,
+            },
+        ];
+
+        assert_eq!(
+            markdown(&reports),
+            "| Day | Part 1 | Part 2 | Runtime | Lines of code | Tests |
+|----:|--------|--------|--------:|--------------:|------:|
+| 10 | pass | visual | 1.50ms | 120 | 1 |
+| 16 | **fail**: 1650, expected 1651 | missing | 80.00ms | 150 | 2 |
+| 12 | pass | unchecked | 100.00ms | 90 | 2 |
+
+1 of 3 days have both parts solved, 2 of 3 recorded answers match, 181.50ms total runtime.
+"
+        );
+    }
+}
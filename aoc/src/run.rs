@@ -0,0 +1,69 @@
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, bail, Context, Result};
+
+use crate::days::{Day, Job};
+use crate::pool::run_ordered;
+
+/// The answers from one job, one for each of its parts
+pub struct JobResult {
+    pub day: &'static Day,
+    pub job: &'static Job,
+    pub answers: Result<Vec<String>>,
+    /// Time spent solving, not counting reading the input
+    pub elapsed: Duration,
+}
+
+/// Runs every job of the given days on `threads` threads. Results are handed to `on_result` in
+/// day and part order no matter which job finishes first.
+pub fn run_days(
+    days: &[&'static Day],
+    root: &Path,
+    threads: usize,
+    on_result: impl FnMut(JobResult),
+) {
+    let inputs = days
+        .iter()
+        .map(|day| {
+            let path = root.join(day.dir).join(day.input);
+            fs::read_to_string(&path).with_context(|| format!("Could not read {}", path.display()))
+        })
+        .collect::<Vec<Result<String>>>();
+
+    let tasks = days
+        .iter()
+        .zip(&inputs)
+        .flat_map(|(&day, input)| day.jobs.iter().map(move |job| (day, job, input)))
+        .collect::<Vec<_>>();
+
+    run_ordered(
+        tasks,
+        threads,
+        |(day, job, input)| {
+            let started = Instant::now();
+            let answers = solve(job, input);
+            JobResult {
+                day,
+                job,
+                answers,
+                elapsed: started.elapsed(),
+            }
+        },
+        on_result,
+    );
+}
+
+fn solve(job: &Job, input: &Result<String>) -> Result<Vec<String>> {
+    let input = input.as_ref().map_err(|e| anyhow!("{e:#}"))?;
+    // most days still unwrap on bad input, that shouldn't take the other days down with it
+    let answers = panic::catch_unwind(AssertUnwindSafe(|| (job.solve)(input)))
+        .map_err(|_| anyhow!("Solution panicked"))??;
+    if answers.len() != job.parts.len() {
+        bail!("Expected {} answers but got {}", job.parts.len(), answers.len());
+    }
+
+    Ok(answers)
+}
@@ -0,0 +1,145 @@
+use std::io::{self, IsTerminal};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+/// How long work has to run before its progress line is shown at all
+const RENDER_DELAY: Duration = Duration::from_millis(250);
+/// Minimum time between redraws of the progress line
+const RENDER_INTERVAL: Duration = Duration::from_millis(100);
+
+static ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Turns progress lines on or off for every `Progress` created afterwards, e.g. when several
+/// simulations run at once and their lines would draw over each other.
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Progress of a long running simulation, drawn as a single line on stderr when it is a
+/// terminal and ignored otherwise. The line is cleared once the work is finished.
+pub struct Progress {
+    label: String,
+    total: Option<u64>,
+    done: u64,
+    started: Instant,
+    last_rendered: Option<Instant>,
+    visible: bool,
+}
+
+impl Progress {
+    /// Progress towards a known amount of work, rendered with an ETA.
+    pub fn with_total(label: &str, total: u64) -> Self {
+        Self::new(label, Some(total))
+    }
+
+    /// Progress through work of unknown size, rendered as a running count.
+    pub fn unbounded(label: &str) -> Self {
+        Self::new(label, None)
+    }
+
+    fn new(label: &str, total: Option<u64>) -> Self {
+        Self {
+            label: label.to_string(),
+            total,
+            done: 0,
+            started: Instant::now(),
+            last_rendered: None,
+            visible: ENABLED.load(Ordering::Relaxed) && io::stderr().is_terminal(),
+        }
+    }
+
+    pub fn inc(&mut self, amount: u64) {
+        self.set(self.done.saturating_add(amount));
+    }
+
+    pub fn set(&mut self, done: u64) {
+        self.done = done;
+        if !self.visible {
+            return;
+        }
+
+        let now = Instant::now();
+        let due = match self.last_rendered {
+            Some(last_rendered) => now - last_rendered >= RENDER_INTERVAL,
+            None => now - self.started >= RENDER_DELAY,
+        };
+        if due {
+            eprint!("\r\x1b[2K{}", self.line(now - self.started));
+            self.last_rendered = Some(now);
+        }
+    }
+
+    /// Clears the progress line, if one was drawn.
+    pub fn finish(&mut self) {
+        if self.last_rendered.take().is_some() {
+            eprint!("\r\x1b[2K");
+        }
+    }
+
+    fn line(&self, elapsed: Duration) -> String {
+        let Some(total) = self.total else {
+            return format!("{}: {}, {:.1}s elapsed", self.label, self.done, elapsed.as_secs_f64());
+        };
+
+        let percent = if total == 0 {
+            100.0
+        } else {
+            self.done as f64 / total as f64 * 100.0
+        };
+        let eta = eta(self.done, total, elapsed)
+            .map_or("?".to_string(), |eta| format!("{:.1}s", eta.as_secs_f64()));
+
+        format!(
+            "{}: {}/{total} ({percent:.0}%), {:.1}s elapsed, ETA {eta}",
+            self.label,
+            self.done,
+            elapsed.as_secs_f64()
+        )
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        self.finish();
+    }
+}
+
+/// Estimates the time left, assuming the remaining work goes at the same rate as so far.
+fn eta(done: u64, total: u64, elapsed: Duration) -> Option<Duration> {
+    if done == 0 {
+        return None;
+    }
+
+    let remaining = total.saturating_sub(done);
+    Some(elapsed.mul_f64(remaining as f64 / done as f64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eta() {
+        assert_eq!(eta(0, 100, Duration::from_secs(1)), None);
+        assert_eq!(eta(25, 100, Duration::from_secs(1)), Some(Duration::from_secs(3)));
+        assert_eq!(eta(100, 100, Duration::from_secs(1)), Some(Duration::ZERO));
+        assert_eq!(eta(150, 100, Duration::from_secs(1)), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_line() {
+        let mut progress = Progress::with_total("Rounds", 10000);
+        progress.set(2500);
+        assert_eq!(
+            progress.line(Duration::from_secs(2)),
+            "Rounds: 2500/10000 (25%), 2.0s elapsed, ETA 6.0s"
+        );
+
+        let mut progress = Progress::unbounded("Sand units at rest");
+        progress.inc(42);
+        assert_eq!(
+            progress.line(Duration::from_millis(1500)),
+            "Sand units at rest: 42, 1.5s elapsed"
+        );
+    }
+}
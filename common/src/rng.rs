@@ -0,0 +1,36 @@
+/// A small deterministic pseudo-random number generator, for tests that build large inputs and
+/// need the same ones on every run
+#[derive(Debug, Clone)]
+pub struct Lcg {
+    state: u64,
+}
+
+impl Lcg {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// The next number, from 0 up to but not including `modulo`
+    pub fn below(&mut self, modulo: u64) -> u64 {
+        self.state = self
+            .state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (self.state >> 33) % modulo
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repeatable() {
+        let mut a = Lcg::new(0x2022);
+        let mut b = Lcg::new(0x2022);
+
+        let numbers = (0..100).map(|_| a.below(10)).collect::<Vec<u64>>();
+        assert!(numbers.iter().all(|&n| n < 10));
+        assert_eq!(numbers, (0..100).map(|_| b.below(10)).collect::<Vec<u64>>());
+    }
+}
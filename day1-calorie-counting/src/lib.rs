@@ -0,0 +1,200 @@
+use std::any::type_name;
+use std::fmt;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Context, Result};
+
+pub mod stats;
+pub mod stream;
+
+/// An unsigned integer type calories are counted in. Totals are summed with `checked_add` so
+/// one that doesn't fit is reported instead of wrapping.
+pub trait Calories:
+    Copy + Ord + Default + fmt::Debug + fmt::Display + FromStr<Err = ParseIntError> + Into<u128>
+{
+    fn checked_add(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_calories {
+    ($($t:ty),*) => {
+        $(impl Calories for $t {
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+        })*
+    };
+}
+
+impl_calories!(u32, u64, u128);
+
+/// Sum of `calories`, or `None` if it overflows `C`
+pub fn checked_sum<C: Calories>(calories: impl IntoIterator<Item = C>) -> Option<C> {
+    calories
+        .into_iter()
+        .try_fold(C::default(), |sum, calories| sum.checked_add(calories))
+}
+
+/// Adds `calories` to the running total of elf `elf`, or says which elf overflowed `C`.
+pub(crate) fn add_calories<C: Calories>(elf: usize, total: C, calories: C) -> Result<C> {
+    total
+        .checked_add(calories)
+        .ok_or_else(|| anyhow!("Total calories of elf {elf} overflow {}", type_name::<C>()))
+}
+
+/// The food items a single elf is carrying, by calories
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf<C: Calories = u64> {
+    items: Vec<C>,
+    total: C,
+}
+
+impl<C: Calories> Elf<C> {
+    /// Errors if the items of elf number `elf` add up to more than `C` can hold
+    fn new(elf: usize, items: Vec<C>) -> Result<Self> {
+        let total = items
+            .iter()
+            .try_fold(C::default(), |total, &calories| add_calories(elf, total, calories))?;
+        Ok(Self { items, total })
+    }
+
+    pub fn items(&self) -> &[C] {
+        &self.items
+    }
+
+    pub fn total(&self) -> C {
+        self.total
+    }
+}
+
+/// Every elf's inventory, parsed once so both parts can be answered from it. Elves are
+/// identified by their position in the input, starting at 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inventory<C: Calories = u64> {
+    elves: Vec<Elf<C>>,
+    /// Elf indices ordered by total calories, most first, ties in input order
+    by_total: Vec<usize>,
+}
+
+impl<C: Calories> FromStr for Inventory<C> {
+    type Err = anyhow::Error;
+
+    /// Parses one item per line, with a blank line between each elf's items
+    fn from_str(elf_inventory: &str) -> Result<Self> {
+        let mut elves = Vec::new();
+        let mut items = Vec::new();
+        for (i, line) in elf_inventory.lines().enumerate() {
+            if line.is_empty() {
+                if !items.is_empty() {
+                    elves.push(Elf::new(elves.len(), std::mem::take(&mut items))?);
+                }
+            } else {
+                let calories = line
+                    .parse::<C>()
+                    .with_context(|| format!("Invalid calories {line:?} on line {}", i + 1))?;
+                items.push(calories);
+            }
+        }
+
+        // the last elf has no blank line after it
+        if !items.is_empty() {
+            elves.push(Elf::new(elves.len(), items)?);
+        }
+
+        let mut by_total = (0..elves.len()).collect::<Vec<usize>>();
+        by_total.sort_by_key(|&elf| std::cmp::Reverse(elves[elf].total));
+
+        Ok(Self { elves, by_total })
+    }
+}
+
+impl<C: Calories> Inventory<C> {
+    pub fn elves(&self) -> &[Elf<C>] {
+        &self.elves
+    }
+
+    pub fn elf(&self, elf: usize) -> Option<&Elf<C>> {
+        self.elves.get(elf)
+    }
+
+    /// The `n` elves carrying the most calories along with their index, most first. There are
+    /// fewer than `n` if there aren't that many elves.
+    pub fn top_n(&self, n: usize) -> Vec<(usize, &Elf<C>)> {
+        self.by_total
+            .iter()
+            .take(n)
+            .map(|&elf| (elf, &self.elves[elf]))
+            .collect()
+    }
+
+    /// Total calories carried by the `n` elves carrying the most. Errors if it overflows `C`.
+    pub fn top_n_total(&self, n: usize) -> Result<C> {
+        checked_sum(self.top_n(n).iter().map(|(_, elf)| elf.total)).with_context(|| {
+            format!("Total calories of the top {n} elves overflow {}", type_name::<C>())
+        })
+    }
+
+    /// Where the elf places when ordered by total calories, starting from 1 for the most.
+    /// Elves with equal totals share a rank.
+    pub fn rank_of(&self, elf: usize) -> Option<usize> {
+        let total = self.elf(elf)?.total;
+        Some(1 + self.elves.iter().filter(|other| other.total > total).count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_top_n() {
+        let inventory = include_str!("../test.txt").parse::<Inventory>().unwrap();
+
+        assert_eq!(inventory.top_n_total(1).unwrap(), 24000);
+        assert_eq!(inventory.top_n_total(3).unwrap(), 45000);
+        assert_eq!(
+            inventory.top_n(3).iter().map(|(elf, _)| *elf).collect::<Vec<usize>>(),
+            vec![3, 2, 4]
+        );
+        assert_eq!(inventory.top_n(10).len(), 5);
+    }
+
+    #[test]
+    fn test_last_elf_has_the_most() {
+        let inventory = "1000\n\n2000\n3000\n".parse::<Inventory>().unwrap();
+
+        assert_eq!(inventory.top_n_total(1).unwrap(), 5000);
+        assert_eq!(inventory.elves().len(), 2);
+    }
+
+    #[test]
+    fn test_lookup_and_rank() {
+        let inventory = "100\n\n300\n\n200\n100\n\n50".parse::<Inventory>().unwrap();
+
+        assert_eq!(inventory.elf(2).unwrap().items(), &[200, 100]);
+        assert_eq!(inventory.elf(4), None);
+        assert_eq!(inventory.rank_of(1), Some(1));
+        assert_eq!(inventory.rank_of(2), Some(1));
+        assert_eq!(inventory.rank_of(0), Some(3));
+        assert_eq!(inventory.rank_of(3), Some(4));
+        assert_eq!(inventory.rank_of(4), None);
+        assert!("100\n\nlots".parse::<Inventory>().is_err());
+    }
+
+    #[test]
+    fn test_overflow() {
+        let input = "1\n\n4294967295\n\n2\n4294967295\n";
+
+        let e = input.parse::<Inventory<u32>>().unwrap_err();
+        assert_eq!(e.to_string(), "Total calories of elf 2 overflow u32");
+        assert!("4294967296".parse::<Inventory<u32>>().is_err());
+
+        let inventory = input.parse::<Inventory<u64>>().unwrap();
+        assert_eq!(inventory.top_n_total(1).unwrap(), 4294967297);
+        assert_eq!(inventory.top_n_total(3).unwrap(), 8589934593);
+
+        let inventory = "4294967295\n\n4294967295".parse::<Inventory<u32>>().unwrap();
+        assert_eq!(inventory.top_n_total(1).unwrap(), u32::MAX);
+        assert!(inventory.top_n_total(2).is_err());
+    }
+}
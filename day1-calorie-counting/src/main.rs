@@ -1,61 +1,62 @@
-use std::cmp;
-use std::collections::BinaryHeap;
+use std::any::type_name;
 use std::env;
-use std::fs;
+use std::fs::{self, File};
+use std::io::BufReader;
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    let file_path = &args[1];
-
-    let elf_inventory =
-        fs::read_to_string(file_path).expect("Should have been able to read the file");
-
-    // part 1
-    let max = get_max_inventory(&elf_inventory);
-    println!("Elf with the max inventory has inventory of {max}");
+use anyhow::{bail, Context, Result};
+use day1_calorie_counting::stats::Stats;
+use day1_calorie_counting::{checked_sum, stream, Calories, Inventory};
 
-    // part 2
-    let top_3 = get_top_3_total(&elf_inventory);
-    println!("Top 3 elves have a total max inventory of {top_3}");
-}
+fn main() -> Result<()> {
+    let args: Vec<String> = env::args().collect();
 
-fn get_max_inventory(elf_inventory: &str) -> u32 {
-    let mut max = 0;
-    let mut acc = 0u32;
-    for line in elf_inventory.lines() {
-        if line.is_empty() {
-            max = cmp::max(acc, max);
-            acc = 0;
-        } else {
-            acc += line.parse::<u32>().unwrap();
-        }
+    // totals are counted in u64 unless another width is asked for with `--width 32|64|128`
+    let width = match args.iter().position(|arg| arg == "--width") {
+        Some(idx) => args.get(idx + 1).context("--width needs a value")?.as_str(),
+        None => "64",
+    };
+    match width {
+        "32" => run::<u32>(&args),
+        "64" => run::<u64>(&args),
+        "128" => run::<u128>(&args),
+        _ => bail!("Unsupported width {width:?}, expected 32, 64 or 128"),
     }
-
-    max
 }
 
-fn get_top_3_total(elf_inventory: &str) -> u32 {
-    let mut heap = BinaryHeap::new();
-
-    let mut acc = 0u32;
-    for line in elf_inventory.lines() {
-        if line.is_empty() {
-            heap.push(acc);
-            acc = 0;
-        } else {
-            acc += line.parse::<u32>().unwrap();
-        }
+fn run<C: Calories>(args: &[String]) -> Result<()> {
+    // the input file can come before or after the flags, only `--width` takes a value
+    let file_path = (1..args.len())
+        .find(|&idx| !args[idx].starts_with("--") && args[idx - 1] != "--width")
+        .map(|idx| &args[idx])
+        .context("Missing the input file to read the inventory from")?;
+
+    if args.iter().any(|arg| arg == "--stats") {
+        let elf_inventory =
+            fs::read_to_string(file_path).expect("Should have been able to read the file");
+        print!("{}", Stats::new(&elf_inventory.parse::<Inventory<C>>()?));
+        return Ok(());
     }
 
-    // add the final acc to to the heap
-    heap.push(acc);
+    let (max, top_3) = if args.iter().any(|arg| arg == "--stream") {
+        // only the top 3 totals are kept, so inventories of any size fit in memory
+        let file = File::open(file_path).expect("Should have been able to read the file");
+        let top = stream::top_n_totals::<C>(BufReader::new(file), 3)?;
+        let top_3 = checked_sum(top.iter().copied()).with_context(|| {
+            format!("Total calories of the top 3 elves overflow {}", type_name::<C>())
+        })?;
+        (top.first().copied().unwrap_or_default(), top_3)
+    } else {
+        let elf_inventory =
+            fs::read_to_string(file_path).expect("Should have been able to read the file");
+        let inventory = elf_inventory.parse::<Inventory<C>>()?;
+        (inventory.top_n_total(1)?, inventory.top_n_total(3)?)
+    };
 
-    let mut sum = 0u32;
-    for i in 0..3 {
-        let max = heap.pop().expect("Not at least 3 elves");
-        println!("Top {i} is {max}");
-        sum += max;
-    }
+    // part 1
+    println!("Elf with the max inventory has inventory of {max}");
+
+    // part 2
+    println!("Top 3 elves have a total max inventory of {top_3}");
 
-    sum
+    Ok(())
 }
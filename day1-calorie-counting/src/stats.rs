@@ -0,0 +1,205 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::{Calories, Inventory};
+
+const REPORT_PERCENTILES: [u32; 5] = [10, 25, 50, 75, 90];
+const REPORT_BUCKETS: usize = 10;
+const REPORT_BAR_WIDTH: usize = 40;
+
+/// Descriptive statistics over the elves of an inventory. Every value is `None` when there are
+/// no elves to describe. Totals are held as `u128` so inventories of any width can be described.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    /// Every elf's total calories, smallest first
+    totals: Vec<u128>,
+    /// Number of elves carrying each number of items
+    item_counts: BTreeMap<usize, usize>,
+}
+
+impl Stats {
+    pub fn new<C: Calories>(inventory: &Inventory<C>) -> Self {
+        let mut totals = inventory
+            .elves()
+            .iter()
+            .map(|elf| elf.total().into())
+            .collect::<Vec<u128>>();
+        totals.sort_unstable();
+
+        let mut item_counts = BTreeMap::new();
+        for elf in inventory.elves() {
+            *item_counts.entry(elf.items().len()).or_insert(0) += 1;
+        }
+
+        Self { totals, item_counts }
+    }
+
+    pub fn elves(&self) -> usize {
+        self.totals.len()
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        if self.totals.is_empty() {
+            return None;
+        }
+        let sum = self.totals.iter().map(|&total| total as f64).sum::<f64>();
+        Some(sum / self.totals.len() as f64)
+    }
+
+    /// The middle total, or the mean of the two middle totals for an even number of elves
+    pub fn median(&self) -> Option<f64> {
+        let n = self.totals.len();
+        match n {
+            0 => None,
+            _ if n % 2 == 1 => Some(self.totals[n / 2] as f64),
+            _ => Some((self.totals[n / 2 - 1] as f64 + self.totals[n / 2] as f64) / 2.0),
+        }
+    }
+
+    /// The smallest total that at least `p` percent of elves carry no more than, using the
+    /// nearest-rank method. `p` is clamped to 0..=100.
+    pub fn percentile(&self, p: f64) -> Option<u128> {
+        if self.totals.is_empty() {
+            return None;
+        }
+        let rank = (p.clamp(0.0, 100.0) / 100.0 * self.totals.len() as f64).ceil() as usize;
+        Some(self.totals[rank.max(1) - 1])
+    }
+
+    /// Population standard deviation of the totals
+    pub fn std_dev(&self) -> Option<f64> {
+        let mean = self.mean()?;
+        let variance = self
+            .totals
+            .iter()
+            .map(|&total| (total as f64 - mean).powi(2))
+            .sum::<f64>()
+            / self.totals.len() as f64;
+        Some(variance.sqrt())
+    }
+
+    pub fn item_counts(&self) -> &BTreeMap<usize, usize> {
+        &self.item_counts
+    }
+
+    /// Splits the range of totals into `buckets` equal ranges and draws one line per range:
+    /// its bounds, the number of elves in it and a bar scaled so the fullest is `width` long.
+    pub fn histogram(&self, buckets: usize, width: usize) -> String {
+        let (Some(&min), Some(&max)) = (self.totals.first(), self.totals.last()) else {
+            return String::new();
+        };
+        let buckets = buckets.max(1);
+        // the range of totals can be all of u128, one more than fits
+        let bucket_size = ((max - min) / buckets as u128).saturating_add(1);
+
+        let mut counts = vec![0; buckets];
+        for &total in &self.totals {
+            counts[(((total - min) / bucket_size) as usize).min(buckets - 1)] += 1;
+        }
+        let fullest = counts.iter().copied().max().unwrap_or(0);
+
+        let bound_width = max.to_string().len();
+        let count_width = fullest.to_string().len();
+        let mut histogram = String::new();
+        for (i, count) in counts.into_iter().enumerate() {
+            let Some(low) = (i as u128)
+                .checked_mul(bucket_size)
+                .and_then(|offset| min.checked_add(offset))
+                .filter(|&low| low <= max)
+            else {
+                break;
+            };
+            // the last bucket also takes the totals past it when the bucket size saturated
+            let high = if i == buckets - 1 {
+                max
+            } else {
+                low.saturating_add(bucket_size - 1).min(max)
+            };
+            let bar = "#".repeat((count * width).div_ceil(fullest));
+            let line = format!("{low:>bound_width$}-{high:>bound_width$} {count:>count_width$} {bar}");
+            histogram.push_str(line.trim_end());
+            histogram.push('\n');
+        }
+        histogram
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Elves: {}", self.elves())?;
+        let (Some(mean), Some(median), Some(std_dev)) = (self.mean(), self.median(), self.std_dev())
+        else {
+            return Ok(());
+        };
+        writeln!(f, "Mean: {mean:.1}")?;
+        writeln!(f, "Median: {median:.1}")?;
+        writeln!(f, "Standard deviation: {std_dev:.1}")?;
+
+        let percentiles = REPORT_PERCENTILES
+            .iter()
+            .filter_map(|&p| Some(format!("p{p} {}", self.percentile(p as f64)?)))
+            .collect::<Vec<String>>();
+        writeln!(f, "Percentiles: {}", percentiles.join(", "))?;
+
+        writeln!(f, "Items per elf:")?;
+        for (items, elves) in &self.item_counts {
+            writeln!(f, "  {items} items: {elves} elves")?;
+        }
+
+        writeln!(f, "Totals:")?;
+        write!(f, "{}", self.histogram(REPORT_BUCKETS, REPORT_BAR_WIDTH))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_stats() {
+        let inventory = include_str!("../test.txt").parse::<Inventory>().unwrap();
+        let stats = Stats::new(&inventory);
+
+        assert_eq!(stats.elves(), 5);
+        assert_eq!(stats.mean(), Some(11000.0));
+        assert_eq!(stats.median(), Some(10000.0));
+        assert_eq!(format!("{:.1}", stats.std_dev().unwrap()), "6985.7");
+        assert_eq!(stats.percentile(0.0), Some(4000));
+        assert_eq!(stats.percentile(25.0), Some(6000));
+        assert_eq!(stats.percentile(90.0), Some(24000));
+        assert_eq!(stats.item_counts(), &BTreeMap::from([(1, 2), (2, 1), (3, 2)]));
+        assert_eq!(
+            stats.histogram(4, 10),
+            " 4000- 9000 2 ##########\n 9001-14001 2 ##########\n14002-19002 0\n19003-24000 1 #####\n"
+        );
+    }
+
+    #[test]
+    fn test_even_and_empty() {
+        let inventory = "1\n\n2\n\n3\n3\n\n10".parse::<Inventory>().unwrap();
+        let stats = Stats::new(&inventory);
+
+        assert_eq!(stats.median(), Some(4.0));
+        assert_eq!(stats.histogram(3, 4), " 1- 4 2 ####\n 5- 8 1 ##\n 9-10 1 ##\n");
+
+        let stats = Stats::new(&"".parse::<Inventory>().unwrap());
+        assert_eq!(stats.mean(), None);
+        assert_eq!(stats.percentile(50.0), None);
+        assert_eq!(stats.histogram(3, 4), "");
+        assert_eq!(stats.to_string(), "Elves: 0\n");
+    }
+
+    #[test]
+    fn test_histogram_of_whole_range() {
+        let inventory = format!("0\n\n{}", u128::MAX).parse::<Inventory<u128>>().unwrap();
+        let stats = Stats::new(&inventory);
+
+        let max = u128::MAX;
+        assert_eq!(stats.histogram(1, 2), format!("{:>39}-{max} 2 ##\n", 0));
+        let half = max / 2;
+        assert_eq!(
+            stats.histogram(2, 2),
+            format!("{:>39}-{half:>39} 1 ##\n{:>39}-{max} 1 ##\n", 0, half + 1)
+        );
+    }
+}
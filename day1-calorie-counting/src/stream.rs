@@ -0,0 +1,105 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::BufRead;
+
+use anyhow::{Context, Result};
+
+use crate::{add_calories, Calories};
+
+/// The `n` largest elf totals, most first, read from an inventory of any size. Only the
+/// current line and a min-heap of `n` totals are held in memory. Errors if any elf's total
+/// overflows `C`.
+pub fn top_n_totals<C: Calories>(mut reader: impl BufRead, n: usize) -> Result<Vec<C>> {
+    let mut top = BinaryHeap::with_capacity(n + 1);
+    let mut line = String::new();
+    let mut line_number = 0;
+    let mut elf = 0;
+    let mut total = None;
+    loop {
+        line.clear();
+        let read = reader
+            .read_line(&mut line)
+            .with_context(|| format!("Could not read line {}", line_number + 1))?;
+        line_number += 1;
+
+        let item = line.trim_end_matches(['\n', '\r']);
+        if read == 0 || item.is_empty() {
+            if let Some(total) = total.take() {
+                keep_if_top(&mut top, n, total);
+                elf += 1;
+            }
+            if read == 0 {
+                break;
+            }
+        } else {
+            let calories = item
+                .parse::<C>()
+                .with_context(|| format!("Invalid calories {item:?} on line {line_number}"))?;
+            total = Some(add_calories(elf, total.unwrap_or_default(), calories)?);
+        }
+    }
+
+    Ok(top.into_sorted_vec().into_iter().map(|Reverse(total)| total).collect())
+}
+
+/// Keeps `total` if it's among the `n` largest seen so far, `top` holding them smallest first.
+fn keep_if_top<C: Calories>(top: &mut BinaryHeap<Reverse<C>>, n: usize, total: C) {
+    if top.len() < n {
+        top.push(Reverse(total));
+    } else if top.peek().is_some_and(|&Reverse(smallest)| total > smallest) {
+        top.pop();
+        top.push(Reverse(total));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Inventory;
+    use common::rng::Lcg;
+
+    /// A deterministic inventory of `elves` elves with a varying number of items each
+    fn generated_inventory(elves: usize) -> String {
+        let mut rng = Lcg::new(0x2022);
+
+        (0..elves)
+            .map(|_| {
+                (0..=rng.below(8))
+                    .map(|_| format!("{}\n", 1 + rng.below(9999)))
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_matches_inventory() {
+        let input = generated_inventory(5000);
+        let inventory = input.parse::<Inventory>().unwrap();
+
+        for n in [0, 1, 3, 100, 6000] {
+            let expected = inventory
+                .top_n(n)
+                .iter()
+                .map(|(_, elf)| elf.total())
+                .collect::<Vec<u64>>();
+            assert_eq!(top_n_totals::<u64>(input.as_bytes(), n).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_example() {
+        let input = include_str!("../test.txt");
+
+        assert_eq!(top_n_totals::<u32>(input.as_bytes(), 3).unwrap(), vec![24000, 11000, 10000]);
+        assert_eq!(top_n_totals::<u32>("1\r\n2\r\n\r\n4\r\n".as_bytes(), 1).unwrap(), vec![4]);
+        assert!(top_n_totals::<u32>("1\n\nx\n".as_bytes(), 1).is_err());
+
+        let e = top_n_totals::<u32>("1\n\n4294967295\n1\n".as_bytes(), 1).unwrap_err();
+        assert_eq!(e.to_string(), "Total calories of elf 1 overflow u32");
+        assert_eq!(
+            top_n_totals::<u64>("1\n\n4294967295\n1\n".as_bytes(), 1).unwrap(),
+            vec![4294967296]
+        );
+    }
+}
@@ -0,0 +1,226 @@
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Result};
+use common::progress::Progress;
+use itertools::Itertools;
+
+#[derive(Debug, Clone, Copy)]
+struct Item {
+    worry_level: u64
+}
+
+#[derive(Debug, Clone)]
+enum MonkeyOp {
+    Add(u64),
+    Mul(u64),
+    Square,
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: Vec<Item>,
+    operation: MonkeyOp,
+    divisibility_test_val: u64,
+    next_monkey_idx_if_true: usize,
+    next_monkey_idx_if_false: usize,
+    num_inspected_items: u64
+}
+
+/// Parses the notes on every monkey, checking that each monkey only throws to monkeys that
+/// exist and never tests for divisibility by zero.
+pub fn parse_notes(notes: &str) -> Result<Vec<Monkey>> {
+    let mut monkeys = Vec::new();
+    for monkey_input in notes.split("\n\n") {
+        let monkey_input = monkey_input.lines().collect::<Vec<&str>>();
+        let [_, starting_items, operation, test, if_true, if_false] = monkey_input[..] else {
+            bail!("Expected 6 lines of notes per monkey, found {}", monkey_input.len());
+        };
+
+        let starting_items = note_value(starting_items, ": ")?
+            .split(", ")
+            .filter(|item| !item.is_empty())
+            .map(|item| {
+                let worry_level = item
+                    .parse::<u64>()
+                    .with_context(|| format!("Invalid worry level {item:?}"))?;
+                Ok(Item { worry_level })
+            })
+            .collect::<Result<Vec<Item>>>()?;
+
+        let (operator, operand) = note_value(operation, "old ")?
+            .split(' ')
+            .collect_tuple()
+            .with_context(|| format!("Unexpected operation {operation:?}"))?;
+
+        let operation = match (operator, operand) {
+            ("*", "old") => MonkeyOp::Square,
+            ("*", operand) => MonkeyOp::Mul(parse_note(operand)?),
+            ("+", operand) => MonkeyOp::Add(parse_note(operand)?),
+            _ => bail!("Unexpected operation {operation:?}"),
+        };
+
+        let divisibility_test_val = parse_note(note_value(test, "by ")?)?;
+        if divisibility_test_val == 0 {
+            bail!("Monkey cannot test for divisibility by zero");
+        }
+
+        let next_monkey_idx_if_true = parse_note(note_value(if_true, "monkey ")?)?;
+        let next_monkey_idx_if_false = parse_note(note_value(if_false, "monkey ")?)?;
+
+        monkeys.push(Monkey {
+            items: starting_items,
+            operation,
+            divisibility_test_val,
+            next_monkey_idx_if_true,
+            next_monkey_idx_if_false,
+            num_inspected_items: 0,
+        });
+    }
+
+    if let Some(idx) = monkeys
+        .iter()
+        .flat_map(|monkey| [monkey.next_monkey_idx_if_true, monkey.next_monkey_idx_if_false])
+        .find(|&idx| idx >= monkeys.len())
+    {
+        bail!("Monkeys cannot throw to monkey {idx}, there are only {}", monkeys.len());
+    }
+
+    Ok(monkeys)
+}
+
+fn note_value<'a>(line: &'a str, separator: &str) -> Result<&'a str> {
+    line.split_once(separator)
+        .map(|(_, value)| value)
+        .with_context(|| format!("Expected {separator:?} in {line:?}"))
+}
+
+fn parse_note<T: FromStr>(value: &str) -> Result<T> {
+    value
+        .parse::<T>()
+        .ok()
+        .with_context(|| format!("Invalid number {value:?}"))
+}
+
+pub fn solve(notes: &str) -> Result<(u64, u64)> {
+    let mut monkeys = parse_notes(notes)?;
+    // println!("{monkeys:#?}");
+    let mut monkeys_part2 = monkeys.clone();
+
+    let p1 = solve_general(&mut monkeys, 20, true)?;
+    let p2 = solve_general(&mut monkeys_part2, 10000, false)?;
+
+    Ok((p1, p2))
+}
+
+fn solve_general(monkeys: &mut [Monkey], num_rounds: usize, get_relieved: bool) -> Result<u64> {
+    let common_multiple = monkeys
+        .iter()
+        .try_fold(1u64, |product, monkey| product.checked_mul(monkey.divisibility_test_val))
+        .context("The product of the divisibility tests doesn't fit in 64 bits")?;
+    let mut progress = Progress::with_total("Monkey rounds", num_rounds as u64);
+    for _round in 1..=num_rounds {
+        for monkey_idx in 0..monkeys.len() {
+            // println!("Monkey {monkey_idx}:");
+            monkeys[monkey_idx].num_inspected_items += monkeys[monkey_idx].items.len() as u64;
+            let monkey = monkeys[monkey_idx].clone();
+            for item in monkey.items {
+                // println!("  Monkey inspects an item with a worry level of {}.", item.worry_level);
+                let mut new_worry_level = match monkeys[monkey_idx].operation {
+                    MonkeyOp::Add(operand) => item.worry_level.checked_add(operand),
+                    MonkeyOp::Mul(operand) => item.worry_level.checked_mul(operand),
+                    MonkeyOp::Square => item.worry_level.checked_mul(item.worry_level),
+                }
+                .with_context(|| {
+                    format!(
+                        "Monkey {monkey_idx} overflowed worry level {} with {:?}",
+                        item.worry_level, monkey.operation
+                    )
+                })?;
+                // println!("    Worry level is now {}.", new_worry_level);
+
+                if get_relieved {
+                    new_worry_level /= 3;
+                } else {
+                    new_worry_level %= common_multiple;
+                }
+                // println!("    Monkey gets bored with item. Worry level is divided by 3 to {}.", new_worry_level);
+
+                let next_monkey_idx = if new_worry_level % monkeys[monkey_idx].divisibility_test_val == 0 {
+                    monkey.next_monkey_idx_if_true
+                } else {
+                    monkey.next_monkey_idx_if_false
+                };
+                monkeys[next_monkey_idx].items.push(Item { worry_level: new_worry_level });
+                // println!("    Item with worry level {} is thrown to monkey {}.", new_worry_level, next_monkey_idx);
+
+            }
+            monkeys[monkey_idx].items.clear();
+        }
+
+        // println!("After round {round}, the monkeys are holding items with these worry levels:");
+        // for (i, monkey) in monkeys.iter().enumerate() {
+            // println!("Monkey {i}: {:?}", monkey.items.iter().map(|item| item.worry_level).collect::<Vec<u64>>());
+        // }
+
+        // if round == 1 || round == 20 || round % 1000 == 0 {
+        //     println!("== After round {round} ==");
+        //     for (i, monkey) in monkeys.iter().enumerate() {
+        //         println!("Monkey {i} inspected items {} times.", monkey.num_inspected_items);
+        //     }
+        // }
+        progress.inc(1);
+    }
+    progress.finish();
+
+    // for (i, monkey) in monkeys.iter().enumerate() {
+        // println!("Monkey {i} inspected items {} times.", monkey.num_inspected_items);
+    // }
+
+    monkeys.sort_by(|a, b| b.num_inspected_items.partial_cmp(&a.num_inspected_items).unwrap());
+
+    monkeys.iter()
+        .take(2)
+        .try_fold(1u64, |product, monkey| product.checked_mul(monkey.num_inspected_items))
+        .context("The level of monkey business doesn't fit in 64 bits")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_part1() {
+        let input = include_str!("../example.txt");
+
+        let (part1, _) = solve(input).unwrap();
+        assert_eq!(part1, 10605);
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        let input = include_str!("../example.txt");
+
+        let (_, part2) = solve(input).unwrap();
+        assert_eq!(part2, 2713310158);
+    }
+
+    #[test]
+    fn test_parse_notes_errors() {
+        let input = include_str!("../example.txt");
+
+        assert!(parse_notes(&input.replace("monkey 3", "monkey 4")).is_err());
+        assert!(parse_notes(&input.replace("by 23", "by 0")).is_err());
+        assert!(parse_notes(&input.replace("old * 19", "old / 19")).is_err());
+        assert!(parse_notes(&input.replace("79, 98", "79, ninety-eight")).is_err());
+    }
+
+    #[test]
+    fn test_worry_level_overflow() {
+        let input = include_str!("../example.txt");
+
+        let e = solve(&input.replace("79, 98", &format!("79, {}", u64::MAX / 2))).unwrap_err();
+        assert!(format!("{e:#}").starts_with("Monkey 0 overflowed worry level"));
+        let huge_tests = input.replace("by 23", &format!("by {}", u64::MAX / 4));
+        assert!(solve(&huge_tests).is_err());
+    }
+}
@@ -0,0 +1,131 @@
+use std::cmp::Ordering;
+
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+use serde_json::{json, Value};
+
+/// Parses a single packet, which must be a list made up of only lists and integers.
+pub fn parse_packet(line: &str) -> Result<Value> {
+    let packet: Value = serde_json::from_str(line).with_context(|| format!("Invalid packet {line:?}"))?;
+    if !packet.is_array() {
+        bail!("Packet must be a list: {line:?}");
+    }
+    validate_packet_data(&packet)?;
+
+    Ok(packet)
+}
+
+fn validate_packet_data(packet_data: &Value) -> Result<()> {
+    match packet_data {
+        Value::Number(n) if n.is_u64() => Ok(()),
+        Value::Array(values) => values.iter().try_for_each(validate_packet_data),
+        _ => bail!("Packets can only contain lists and integers, found {packet_data}"),
+    }
+}
+
+/// Parses the packets as the pairs separated by blank lines used in part 1.
+pub fn parse_packet_pairs(input: &str) -> Result<Vec<(Value, Value)>> {
+    input.split("\n\n")
+        .map(|line_pair| {
+            line_pair.lines()
+                .map(parse_packet)
+                .collect::<Result<Vec<Value>>>()?
+                .into_iter()
+                .collect_tuple::<(Value, Value)>()
+                .with_context(|| format!("Expected a pair of packets: {line_pair:?}"))
+        })
+        .collect()
+}
+
+/// Parses every packet, ignoring the blank lines between pairs as part 2 does.
+pub fn parse_packets(input: &str) -> Result<Vec<Value>> {
+    input.lines()
+        .filter(|line| !line.is_empty())
+        .map(parse_packet)
+        .collect()
+}
+
+fn compare(lhs: &Value, rhs: &Value) -> Option<Ordering> {
+    match (lhs, rhs) {
+        (Value::Number(a), Value::Number(b)) => match a.as_u64().cmp(&b.as_u64()) {
+            Ordering::Equal => None,
+            order => Some(order),
+        },
+        (Value::Array(a), Value::Array(b)) => {
+            if a.is_empty() || b.is_empty() {
+                match a.len().cmp(&b.len()) {
+                    Ordering::Equal => None,
+                    order => Some(order),
+                }
+            } else if let Some(v) = compare(&a[0], &b[0]) {
+                Some(v)
+            } else {
+                compare(&json!(a[1..]), &json!(b[1..]))
+            }
+        }
+        (Value::Number(a), Value::Array(b)) => compare(&json!(vec![a]), &json!(b)),
+        (Value::Array(a), Value::Number(b)) => compare(&json!(a), &json!(vec![b])),
+        _ => unreachable!(),
+    }
+}
+
+pub fn solve(input: &str) -> Result<usize> {
+    let sum = parse_packet_pairs(input)?
+        .into_iter()
+        .map(|(l, r)| compare(&l, &r))
+        .enumerate()
+        .filter(|(_, p)| p.is_some() && matches!(p.unwrap(), Ordering::Less))
+        .map(|(i, _)| i + 1)
+        .sum::<usize>();
+    // packets.extend([json!([[2]]), json!([[6]])]);
+    // packets.sort_by(|a, b| compare(a, b).unwrap());
+
+    // let dp1 = packets.iter().position(|p| *p == json!([[2]])).unwrap() + 1;
+    // let dp2 = packets.iter().position(|p| *p == json!([[6]])).unwrap() + 1;
+    // println!("p2: {:?}", dp1 * dp2);
+
+    Ok(sum)
+}
+
+pub fn solve2(input: &str) -> Result<usize> {
+    let sorted_packets = parse_packets(input)?
+        .into_iter()
+        .chain([json!([[2]]), json!([[6]])])
+        .sorted_by(|l, r| compare(l, r).unwrap())
+        .collect::<Vec<_>>();
+
+    let d1 = sorted_packets.iter().position(|p| *p == json!([[2]])).unwrap() + 1;
+    let d2 = sorted_packets.iter().position(|p| *p == json!([[6]])).unwrap() + 1;
+
+    Ok(d1 * d2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_part1() {
+        let input = include_str!("../example.txt");
+
+        let part1 = solve(input).unwrap();
+        assert_eq!(part1, 13);
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        let input = include_str!("../example.txt");
+        let part2 = solve2(input).unwrap();
+        assert_eq!(part2, 140);
+    }
+
+    #[test]
+    fn test_parse_packet_errors() {
+        assert!(parse_packet("[1,[2,[3]]]").is_ok());
+        assert!(parse_packet("[1,[2,[3]]").is_err());
+        assert!(parse_packet("[1,-2]").is_err());
+        assert!(parse_packet("[1,\"2\"]").is_err());
+        assert!(parse_packet("1").is_err());
+        assert!(parse_packet_pairs("[1]\n[2]\n[3]").is_err());
+    }
+}
@@ -0,0 +1,183 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Result};
+use common::progress::Progress;
+use itertools::Itertools;
+
+#[derive(Clone, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+/// The points of a rock structure, each joined to the next by a horizontal or vertical line
+pub type RockPath = Vec<Point>;
+
+impl FromStr for Point {
+    type Err = anyhow::Error;
+
+    /// Takes a string in the form of "x,y" and returns a Point
+    fn from_str(value: &str) -> Result<Self> {
+        let (x, y) = value
+            .split(',')
+            .collect_tuple()
+            .with_context(|| format!("Expected a point in the form \"x,y\": {value:?}"))?;
+        Ok(Point {
+            x: x.parse().with_context(|| format!("Invalid x coordinate in {value:?}"))?,
+            y: y.parse().with_context(|| format!("Invalid y coordinate in {value:?}"))?,
+        })
+    }
+}
+
+impl Point {
+    pub fn in_abyss(&self, lower_bound: i32) -> bool {
+        self.y >= lower_bound
+    }
+
+    pub fn blocked(&self, points: &HashSet<Self>) -> bool {
+        let point_down = Point { x: self.x, y: self.y + 1 };
+        let point_down_left = Point { x: self.x - 1, y: self.y + 1 };
+        let point_down_right = Point { x: self.x + 1, y: self.y + 1 };
+
+        points.contains(&point_down) && points.contains(&point_down_left) && points.contains(&point_down_right)
+    }
+}
+
+pub fn solve2(input: &str) -> Result<usize> {
+    // parse rock structures
+    let mut rocks = parse_rock_structures(input)?;
+    let floor = lowest_rock(&rocks)? + 2;
+
+    // simulate falling sand
+    let origin = Point { x: 500, y: 0 };
+    let mut sand_units_at_rest = HashSet::new();
+    let mut progress = Progress::unbounded("Sand units at rest");
+    let mut sand_unit_in_motion = origin.clone();
+    while !origin.blocked(&sand_units_at_rest) {
+        let point_down = Point { x: sand_unit_in_motion.x, y: sand_unit_in_motion.y + 1 };
+        let point_down_left = Point { x: sand_unit_in_motion.x - 1, y: sand_unit_in_motion.y + 1 };
+        let point_down_right = Point { x: sand_unit_in_motion.x + 1, y: sand_unit_in_motion.y + 1 };
+        if !rocks.contains(&point_down) && point_down.y != floor {
+            sand_unit_in_motion = point_down;
+        } else if !rocks.contains(&point_down_left) && point_down.y != floor {
+            sand_unit_in_motion = point_down_left;
+        } else if !rocks.contains(&point_down_right) && point_down.y != floor {
+            sand_unit_in_motion = point_down_right;
+        } else {
+            sand_units_at_rest.insert(sand_unit_in_motion.clone());
+            rocks.insert(sand_unit_in_motion);
+            sand_unit_in_motion = origin.clone();
+            progress.inc(1);
+        }
+    }
+    progress.finish();
+
+    Ok(sand_units_at_rest.len() + 1)
+}
+
+pub fn solve(input: &str) -> Result<usize> {
+    // parse rock structures
+    let mut rocks = parse_rock_structures(input)?;
+    let lower_bound = lowest_rock(&rocks)?;
+
+    // simulate falling sand
+    let origin = Point { x: 500, y: 0 };
+    let mut sand_units_at_rest = HashSet::new();
+    let mut sand_unit_in_motion = origin.clone();
+    while !sand_unit_in_motion.in_abyss(lower_bound) {
+        let point_down = Point { x: sand_unit_in_motion.x, y: sand_unit_in_motion.y + 1 };
+        let point_down_left = Point { x: sand_unit_in_motion.x - 1, y: sand_unit_in_motion.y + 1 };
+        let point_down_right = Point { x: sand_unit_in_motion.x + 1, y: sand_unit_in_motion.y + 1 };
+        if !rocks.contains(&point_down) {
+            sand_unit_in_motion = point_down;
+        } else if !rocks.contains(&point_down_left) {
+            sand_unit_in_motion = point_down_left;
+        } else if !rocks.contains(&point_down_right) {
+            sand_unit_in_motion = point_down_right;
+        } else {
+            sand_units_at_rest.insert(sand_unit_in_motion.clone());
+            rocks.insert(sand_unit_in_motion);
+            sand_unit_in_motion = origin.clone();
+        }
+    }
+
+    Ok(sand_units_at_rest.len())
+}
+
+/// Parses the scan of rock structures, one path per line in the form "x,y -> x,y -> ...".
+pub fn parse_rock_paths(input: &str) -> Result<Vec<RockPath>> {
+    input.lines()
+        .map(|line| {
+            let path = line.replace(" ->", "")
+                .split_whitespace()
+                .map(str::parse)
+                .collect::<Result<RockPath>>()?;
+
+            if let Some((start, end)) = path.iter()
+                .tuple_windows()
+                .find(|(start, end)| start.x != end.x && start.y != end.y)
+            {
+                bail!("Rock formation not contiguous between {start:?} and {end:?}");
+            }
+            Ok(path)
+        })
+        .collect()
+}
+
+fn parse_rock_structures(input: &str) -> Result<HashSet<Point>> {
+    let mut rocks = HashSet::new();
+    for path in parse_rock_paths(input)? {
+        for (start, end) in path.iter().tuple_windows() {
+            if start.x == end.x {
+                let bottom = i32::min(start.y, end.y);
+                let top = i32::max(start.y, end.y);
+                for y in bottom..=top {
+                    rocks.insert(Point { x: start.x, y });
+                }
+            } else {
+                let left = i32::min(start.x, end.x);
+                let right = i32::max(start.x, end.x);
+                for x in left..=right {
+                    rocks.insert(Point { x, y: start.y });
+                }
+            }
+        }
+    }
+    Ok(rocks)
+}
+
+fn lowest_rock(rocks: &HashSet<Point>) -> Result<i32> {
+    rocks.iter()
+        .map(|rock| rock.y)
+        .max()
+        .context("No rock structures in the scan")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_part1() {
+        let input = include_str!("../example.txt");
+
+        let part1 = solve(input).unwrap();
+        assert_eq!(part1, 24);
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        let input = include_str!("../example.txt");
+
+        let part1 = solve2(input).unwrap();
+        assert_eq!(part1, 93);
+    }
+
+    #[test]
+    fn test_parse_rock_paths_errors() {
+        assert!(parse_rock_paths("498,4 -> 497,6").is_err());
+        assert!(parse_rock_paths("498,4 -> 498").is_err());
+        assert!(parse_rock_paths("498,4 -> 498,four").is_err());
+    }
+}
@@ -0,0 +1,198 @@
+use std::collections::HashSet;
+use std::collections::HashMap;
+
+use anyhow::{bail, Context, Result};
+use common::progress::Progress;
+use petgraph::{prelude::*, Graph};
+use petgraph::algo::floyd_warshall;
+
+const TIME_BUDGET: u32 = 30;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Valve {
+    name: String,
+    flow_rate: u32,
+    adjacent_valves: HashSet<String>,
+}
+
+impl Valve {
+    fn new(name: &str, flow_rate: u32) -> Self {
+        Self {
+            name: name.to_string(),
+            flow_rate,
+            adjacent_valves: HashSet::new(),
+        }
+    }
+}
+
+/// A valve worth opening, along with the bit that marks it as open in the search state
+struct FlowingValve {
+    node_idx: NodeIndex,
+    flow_rate: u32,
+    bitmask: u64,
+}
+
+/// Parses the scan of valves, e.g. "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB",
+/// checking that every tunnel leads to a valve in the scan.
+pub fn parse_valves(input: &str) -> Result<Vec<Valve>> {
+    let mut valves = Vec::new();
+    for line in input.lines() {
+        let (valve_desc, adjacent_valves_desc) = line
+            .split_once(';')
+            .with_context(|| format!("Expected ';' in {line:?}"))?;
+        let valve_desc = valve_desc.split(' ').collect::<Vec<&str>>();
+
+        let ["Valve", name, "has", "flow", flow_rate] = valve_desc[..] else {
+            bail!("Unexpected valve description {line:?}");
+        };
+
+        let flow_rate = flow_rate
+            .strip_prefix("rate=")
+            .and_then(|flow_rate| flow_rate.parse::<u32>().ok())
+            .with_context(|| format!("Invalid flow rate in {line:?}"))?;
+
+        let mut valve = Valve::new(name, flow_rate);
+
+        let adjacent_valves_names = if let Some(valves) = adjacent_valves_desc.split_once("valves ") {
+            valves.1
+        } else if let Some(valves) = adjacent_valves_desc.split_once("valve ") {
+            valves.1
+        } else {
+            bail!("Missing tunnels in {line:?}");
+        };
+
+        for adjacent_valve_name in adjacent_valves_names.split(", ") {
+            valve.adjacent_valves.insert(adjacent_valve_name.to_string());
+        }
+
+        valves.push(valve);
+    }
+
+    let names = valves.iter().map(|valve| valve.name.as_str()).collect::<HashSet<&str>>();
+    if names.len() != valves.len() {
+        bail!("Valves must have unique names");
+    }
+    for valve in &valves {
+        if let Some(missing) = valve.adjacent_valves.iter().find(|name| !names.contains(name.as_str())) {
+            bail!("Tunnel from {} leads to unknown valve {missing}", valve.name);
+        }
+    }
+
+    Ok(valves)
+}
+
+pub fn solve(input: &str) -> Result<u32> {
+    let valves = parse_valves(input)?;
+
+    let mut graph = Graph::<&str, u32>::new();
+    let node_indices = valves
+        .iter()
+        .map(|valve| (valve.name.as_str(), graph.add_node(valve.name.as_str())))
+        .collect::<HashMap<&str, NodeIndex>>();
+
+    // once all nodes are added to the graph, add the edges
+    for valve in &valves {
+        for adjacent_valve in &valve.adjacent_valves {
+            graph.add_edge(node_indices[valve.name.as_str()], node_indices[adjacent_valve.as_str()], 1);
+        }
+    }
+
+    let dist = floyd_warshall(&graph, |edge| *edge.weight()).unwrap();
+
+    // only valves that release pressure are worth walking to, each gets a bit in the state
+    let flows = valves
+        .iter()
+        .filter(|valve| valve.flow_rate > 0)
+        .zip(0..u64::BITS)
+        .map(|(valve, i)| FlowingValve {
+            node_idx: node_indices[valve.name.as_str()],
+            flow_rate: valve.flow_rate,
+            bitmask: 1 << i,
+        })
+        .collect::<Vec<FlowingValve>>();
+    if flows.len() < valves.iter().filter(|valve| valve.flow_rate > 0).count() {
+        bail!("Cannot track more than {} valves with a flow rate", u64::BITS);
+    }
+
+    let start = *node_indices.get("AA").context("Missing starting valve AA")?;
+
+    let mut answer = HashMap::new();
+    let mut progress = Progress::unbounded("Valve orderings searched");
+    visit(&flows, &dist, start, TIME_BUDGET, 0, 0, &mut answer, &mut progress)?;
+    progress.finish();
+
+    Ok(*answer.values().max().unwrap())
+}
+
+/// Records the best pressure released for each set of open valves reachable from
+/// `current_valve` within the remaining `budget` minutes.
+#[allow(clippy::too_many_arguments)]
+fn visit(
+    flows: &[FlowingValve],
+    dist: &HashMap<(NodeIndex, NodeIndex), u32>,
+    current_valve: NodeIndex,
+    budget: u32,
+    state: u64,
+    flow: u32,
+    answer: &mut HashMap<u64, u32>,
+    progress: &mut Progress,
+) -> Result<()> {
+    progress.inc(1);
+    let best_answer_for_state = answer.entry(state).or_insert(0);
+    *best_answer_for_state = (*best_answer_for_state).max(flow);
+
+    for next_valve in flows {
+        let valve_open = next_valve.bitmask & state != 0;
+        // walking there and opening the valve takes one minute per tunnel, plus one
+        let new_budget = dist[&(current_valve, next_valve.node_idx)]
+            .checked_add(1)
+            .and_then(|cost| budget.checked_sub(cost))
+            .unwrap_or(0);
+        if valve_open || new_budget == 0 {
+            continue;
+        }
+
+        let new_flow = new_budget
+            .checked_mul(next_valve.flow_rate)
+            .and_then(|released| flow.checked_add(released))
+            .context("Pressure released doesn't fit in 32 bits")?;
+        visit(
+            flows,
+            dist,
+            next_valve.node_idx,
+            new_budget,
+            state | next_valve.bitmask,
+            new_flow,
+            answer,
+            progress,
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_part1() {
+        let input = include_str!("../example.txt");
+
+        let part1 = solve(input).unwrap();
+        assert_eq!(part1, 1651);
+    }
+
+    #[test]
+    fn test_parse_valves_errors() {
+        assert!(parse_valves("Valve AA has flow rate=0; tunnel leads to valve BB").is_err());
+        assert!(parse_valves("Valve AA has flow rate=-1; tunnel leads to valve AA").is_err());
+        assert!(parse_valves("Valve AA has flow rate=0, tunnel leads to valve AA").is_err());
+    }
+
+    #[test]
+    fn test_pressure_overflow() {
+        let input = include_str!("../example.txt")
+            .replace("rate=20;", &format!("rate={};", u32::MAX / 4));
+        assert!(solve(&input).is_err());
+    }
+}
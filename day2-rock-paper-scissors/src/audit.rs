@@ -0,0 +1,119 @@
+use std::fmt::Write;
+
+use anyhow::Result;
+
+use crate::{play, Outcome, Round, Rules, StrategyInterpretation};
+
+/// How one round of the guide was played and scored
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoundScore {
+    /// Line of the round in the guide, starting at 1
+    pub line: usize,
+    pub opponent: usize,
+    pub mine: usize,
+    pub outcome: Outcome,
+    pub shape_score: u64,
+    pub outcome_score: u64,
+    /// Total score of this round and every one before it
+    pub running_total: u64,
+}
+
+/// Scores every round of the guide, reading it with `interpretation`
+pub fn breakdown(
+    rounds: &[Round],
+    rules: &Rules,
+    interpretation: &impl StrategyInterpretation,
+) -> Result<Vec<RoundScore>> {
+    let mut running_total = 0;
+    let shapes = play(rounds, rules, interpretation)?;
+    Ok(rounds
+        .iter()
+        .zip(shapes)
+        .enumerate()
+        .map(|(i, (round, mine))| {
+            let outcome = rules.outcome(mine, round.opponent.0);
+            let shape_score = rules.shapes()[mine].score;
+            let outcome_score = rules.outcome_score(outcome);
+            running_total += shape_score + outcome_score;
+            RoundScore {
+                line: i + 1,
+                opponent: round.opponent.0,
+                mine,
+                outcome,
+                shape_score,
+                outcome_score,
+                running_total,
+            }
+        })
+        .collect())
+}
+
+/// Renders the breakdown as a table with one row per round, columns aligned
+pub fn table(breakdown: &[RoundScore], rules: &Rules) -> String {
+    let header = ["Line", "Opponent", "Me", "Outcome", "Shape", "Outcome", "Total"];
+    let rows = breakdown.iter().map(|round| {
+        [
+            round.line.to_string(),
+            rules.shapes()[round.opponent].name.clone(),
+            rules.shapes()[round.mine].name.clone(),
+            format!("{:?}", round.outcome),
+            round.shape_score.to_string(),
+            round.outcome_score.to_string(),
+            round.running_total.to_string(),
+        ]
+    });
+    let rows = std::iter::once(header.map(String::from)).chain(rows).collect::<Vec<_>>();
+
+    let mut widths = [0; 7];
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let mut table = String::new();
+    for row in &rows {
+        let mut line = String::new();
+        for (col, (cell, &width)) in row.iter().zip(&widths).enumerate() {
+            // names are left aligned, numbers right aligned
+            let _ = match col {
+                1..=3 => write!(line, "{cell:<width$}  "),
+                _ => write!(line, "{cell:>width$}  "),
+            };
+        }
+        table.push_str(line.trim_end());
+        table.push('\n');
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_strategy_guide, total_score, AchieveOutcome, PlayShape};
+
+    #[test]
+    fn test_breakdown() {
+        let rules = Rules::classic();
+        let rounds = parse_strategy_guide(include_str!("../example1.txt"), &rules).unwrap();
+
+        let shapes = breakdown(&rounds, &rules, &PlayShape).unwrap();
+        assert_eq!(
+            table(&shapes, &rules),
+            "\
+Line  Opponent  Me        Outcome  Shape  Outcome  Total
+   1  Rock      Paper     Win          2        6      8
+   2  Paper     Rock      Lose         1        0      9
+   3  Scissors  Scissors  Draw         3        3     15
+"
+        );
+
+        let outcomes = breakdown(&rounds, &rules, &AchieveOutcome).unwrap();
+        assert_eq!(
+            outcomes.last().unwrap().running_total,
+            total_score(&rounds, &rules, &AchieveOutcome).unwrap()
+        );
+        assert_eq!(outcomes[2].mine, 0);
+        assert_eq!(outcomes[2].outcome, Outcome::Win);
+    }
+}
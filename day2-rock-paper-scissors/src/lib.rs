@@ -0,0 +1,192 @@
+use anyhow::{Context, Result};
+use itertools::Itertools;
+
+pub mod audit;
+pub mod optimizer;
+pub mod rules;
+
+pub use rules::{Outcome, Rules, Shape};
+
+/// The shape the opponent plays in a round, from the first column of the guide. It holds the
+/// shape's index in `Rules::shapes()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OpponentMove(pub usize);
+
+/// The second column of the guide, whose meaning depends on the `StrategyInterpretation`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ResponseCode(pub String);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Round {
+    pub opponent: OpponentMove,
+    pub response: ResponseCode,
+}
+
+/// One way of reading the response column: it decides which shape to play in a round. The
+/// rounds are scored the same way whatever the interpretation.
+pub trait StrategyInterpretation {
+    /// The index of the shape to play against `opponent`, or an error if `response` means
+    /// nothing to this interpretation
+    fn shape_to_play(
+        &self,
+        rules: &Rules,
+        opponent: OpponentMove,
+        response: &ResponseCode,
+    ) -> Result<usize>;
+}
+
+/// Part 1: the response column is the shape to play, X for Rock, Y for Paper, and Z for
+/// Scissors in the classic rules
+pub struct PlayShape;
+
+impl StrategyInterpretation for PlayShape {
+    fn shape_to_play(
+        &self,
+        rules: &Rules,
+        _opponent: OpponentMove,
+        response: &ResponseCode,
+    ) -> Result<usize> {
+        rules
+            .response_shape(&response.0)
+            .with_context(|| format!("Unknown response shape {:?}", response.0))
+    }
+}
+
+/// Part 2: the response column is how the round needs to end, X means lose, Y means draw and
+/// Z means win in the classic rules
+pub struct AchieveOutcome;
+
+impl StrategyInterpretation for AchieveOutcome {
+    fn shape_to_play(
+        &self,
+        rules: &Rules,
+        opponent: OpponentMove,
+        response: &ResponseCode,
+    ) -> Result<usize> {
+        let outcome = rules
+            .response_outcome(&response.0)
+            .with_context(|| format!("Unknown outcome {:?}", response.0))?;
+        Ok(rules.shape_for(opponent.0, outcome))
+    }
+}
+
+/// Parses each line of the guide into a round, checking the opponent's shape exists in `rules`.
+/// Response codes are only checked when the guide is played.
+pub fn parse_strategy_guide(strategy_guide: &str, rules: &Rules) -> Result<Vec<Round>> {
+    strategy_guide
+        .lines()
+        .enumerate()
+        .map(|(i, round)| {
+            let (opponent, response) = round.split(' ').collect_tuple().with_context(|| {
+                format!("Expected two columns in round {round:?} on line {}", i + 1)
+            })?;
+            let opponent = rules.opponent_shape(opponent).with_context(|| {
+                format!("Unknown opponent shape {opponent:?} on line {}", i + 1)
+            })?;
+            Ok(Round {
+                opponent: OpponentMove(opponent),
+                response: ResponseCode(response.to_string()),
+            })
+        })
+        .collect()
+}
+
+/// The shape played in each round, in order, when following the guide
+pub fn play(
+    rounds: &[Round],
+    rules: &Rules,
+    interpretation: &impl StrategyInterpretation,
+) -> Result<Vec<usize>> {
+    rounds
+        .iter()
+        .enumerate()
+        .map(|(i, round)| {
+            interpretation
+                .shape_to_play(rules, round.opponent, &round.response)
+                .with_context(|| format!("Invalid response on line {}", i + 1))
+        })
+        .collect()
+}
+
+/// Total score from following the guide, reading it with `interpretation`
+pub fn total_score(
+    rounds: &[Round],
+    rules: &Rules,
+    interpretation: &impl StrategyInterpretation,
+) -> Result<u64> {
+    let shapes = play(rounds, rules, interpretation)?;
+    Ok(rounds
+        .iter()
+        .zip(shapes)
+        .map(|(round, mine)| rules.score(mine, round.opponent.0))
+        .sum())
+}
+
+pub fn part_1(strategy_guide: &str, rules: &Rules) -> Result<u64> {
+    total_score(&parse_strategy_guide(strategy_guide, rules)?, rules, &PlayShape)
+}
+
+pub fn part_2(strategy_guide: &str, rules: &Rules) -> Result<u64> {
+    total_score(&parse_strategy_guide(strategy_guide, rules)?, rules, &AchieveOutcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let strategy_guide = include_str!("../example1.txt");
+        let rules = Rules::classic();
+
+        assert_eq!(part_1(strategy_guide, &rules).unwrap(), 15);
+        assert_eq!(part_2(strategy_guide, &rules).unwrap(), 12);
+    }
+
+    #[test]
+    fn test_lizard_spock_guide() {
+        let rules = include_str!("../rules/rock-paper-scissors-lizard-spock.txt")
+            .parse::<Rules>()
+            .unwrap();
+
+        // Spock (2) vaporizes Rock, Rock (1) crushes Scissors, Lizard (4) draws
+        assert_eq!(part_1("A W\nE V\nD Y", &rules).unwrap(), 8 + 7 + 7);
+        // Paper (3) beats Rock and loses to Lizard, outscoring Spock both times
+        assert_eq!(part_2("A Z\nD X", &rules).unwrap(), 9 + 3);
+    }
+
+    #[test]
+    fn test_new_interpretation() {
+        /// Copies the opponent whatever the response column says
+        struct Mirror;
+
+        impl StrategyInterpretation for Mirror {
+            fn shape_to_play(
+                &self,
+                _rules: &Rules,
+                opponent: OpponentMove,
+                _response: &ResponseCode,
+            ) -> Result<usize> {
+                Ok(opponent.0)
+            }
+        }
+
+        let rules = Rules::classic();
+        let rounds = parse_strategy_guide(include_str!("../example1.txt"), &rules).unwrap();
+
+        assert_eq!(play(&rounds, &rules, &Mirror).unwrap(), vec![0, 1, 2]);
+        assert_eq!(total_score(&rounds, &rules, &Mirror).unwrap(), 4 + 5 + 6);
+    }
+
+    #[test]
+    fn test_invalid_guide() {
+        let rules = Rules::classic();
+
+        assert!(part_1("A Y\nB", &rules).is_err());
+        assert!(part_1("A Y\nD X", &rules).is_err());
+        assert!(part_2("A W", &rules).is_err());
+
+        let e = part_1("A Y\nB W", &rules).unwrap_err();
+        assert_eq!(format!("{e:#}"), "Invalid response on line 2: Unknown response shape \"W\"");
+    }
+}
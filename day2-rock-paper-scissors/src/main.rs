@@ -1,119 +1,94 @@
-use std::collections::HashMap;
 use std::env;
 use std::fs;
 
-use itertools::Itertools;
+use anyhow::{Context, Result};
+use day2_rock_paper_scissors::audit::{breakdown, table};
+use day2_rock_paper_scissors::optimizer::{best_score, head_to_head, worst_score, ScoreDistribution};
+use day2_rock_paper_scissors::{
+    parse_strategy_guide, play, total_score, AchieveOutcome, OpponentMove, PlayShape, Rules,
+};
 
-#[derive(Hash, Eq, PartialEq, Clone)]
-enum Shape {
-    Rock = 1,
-    Paper = 2,
-    Scissors = 3,
-}
-
-#[derive(Hash)]
-struct Hand {
-    shape: Shape,
-    score: u8,
-}
-
-impl Hand {
-    fn new(val: &str) -> Self {
-        match val {
-            "A" | "X" => Self {
-                shape: Shape::Rock,
-                score: 1,
-            },
-            "B" | "Y" => Self {
-                shape: Shape::Paper,
-                score: 2,
-            },
-            "C" | "Z" => Self {
-                shape: Shape::Scissors,
-                score: 3,
-            },
-            s => panic!("Unexpected shape string: {s}"),
-        }
-    }
-}
-
-fn main() {
+fn main() -> Result<()> {
     let args: Vec<String> = env::args().collect();
     let file_path = &args[1];
 
     let strategy_guide =
         fs::read_to_string(file_path).expect("Should have been able to read the file");
-
-    let winners: HashMap<Shape, Shape> = HashMap::from([
-        (Shape::Rock, Shape::Paper),
-        (Shape::Paper, Shape::Scissors),
-        (Shape::Scissors, Shape::Rock),
-    ]);
-
-    let total_score = part_1(&strategy_guide, &winners);
-    println!("Total score for part 1 according to strategy: {total_score}");
-
-    let total_score = part_2(&strategy_guide, &winners);
-    println!("Total score for part 2 according to strategy: {total_score}");
-}
-
-/// For part 1, the second column is what you should play in response: X for Rock, Y for
-/// Paper, and Z for Scissors.
-fn part_1(strategy_guide: &str, winners: &HashMap<Shape, Shape>) -> u64 {
-    let mut total_score = 0;
-    for game in strategy_guide.lines() {
-        let (opponent_hand, my_hand) = &game
-            .split(' ')
-            .collect::<Vec<&str>>()
-            .iter()
-            .map(|s| Hand::new(s))
-            .collect_tuple()
-            .unwrap();
-
-        total_score += my_hand.score as u64
-            + if winners[&opponent_hand.shape] == my_hand.shape {
-                6
-            } else if opponent_hand.shape == my_hand.shape {
-                3
-            } else {
-                0
-            }
+    let option = |name: &str| -> Result<Option<&str>> {
+        let Some(idx) = args.iter().position(|arg| arg == name) else {
+            return Ok(None);
+        };
+        let value = args.get(idx + 1).with_context(|| format!("{name} needs a file"))?;
+        Ok(Some(value.as_str()))
+    };
+
+    // `--rules FILE` plays a different game, e.g. rules/rock-paper-scissors-lizard-spock.txt
+    let rules = match option("--rules")? {
+        Some(rules_path) => fs::read_to_string(rules_path)
+            .expect("Should have been able to read the rules")
+            .parse::<Rules>()?,
+        None => Rules::classic(),
+    };
+    let rounds = parse_strategy_guide(&strategy_guide, &rules)?;
+
+    let part_1 = total_score(&rounds, &rules, &PlayShape)?;
+    println!("Total score for part 1 according to strategy: {part_1}");
+
+    let part_2 = total_score(&rounds, &rules, &AchieveOutcome)?;
+    println!("Total score for part 2 according to strategy: {part_2}");
+
+    // `--breakdown` shows how each round was scored, to check a total line by line
+    if args.iter().any(|arg| arg == "--breakdown") {
+        println!("\nPart 1, playing the shape given:");
+        print!("{}", table(&breakdown(&rounds, &rules, &PlayShape)?, &rules));
+        println!("\nPart 2, playing for the outcome given:");
+        print!("{}", table(&breakdown(&rounds, &rules, &AchieveOutcome)?, &rules));
     }
 
-    total_score
-}
-
-/// For the part 2, the second column is as follows: X means you need to lose, Y means
-/// you need to end the round in a draw, and Z means you need to win.
-fn part_2(strategy_guide: &str, winners: &HashMap<Shape, Shape>) -> u64 {
-    let losers: HashMap<Shape, Shape> = HashMap::from([
-        (Shape::Paper, Shape::Rock),
-        (Shape::Scissors, Shape::Paper),
-        (Shape::Rock, Shape::Scissors),
-    ]);
-
-    let mut total_score = 0;
-    for game in strategy_guide.lines() {
-        let (opponent_hand, my_hand) = &game
-            .split(' ')
-            .collect::<Vec<&str>>()
-            .iter()
-            .map(|s| Hand::new(s))
-            .collect_tuple()
-            .unwrap();
+    if args.iter().any(|arg| arg == "--analyze") {
+        let opponent = rounds.iter().map(|round| round.opponent).collect::<Vec<OpponentMove>>();
+        let random = ScoreDistribution::random_responses(&opponent, &rules);
+
+        println!("Best possible score: {}", best_score(&opponent, &rules));
+        println!("Worst possible score: {}", worst_score(&opponent, &rules));
+        println!("Random responses score {:.1} on average", random.mean());
+        for (part, score) in [(1, part_1), (2, part_2)] {
+            println!(
+                "Part {part} strategy scores at least as much as {:.2}% of random responses",
+                100.0 * random.at_most(score)
+            );
+        }
+    }
 
-        let score = match my_hand.shape {
-            // lose
-            Shape::Rock => losers[&opponent_hand.shape].clone() as u64,
-            // draw
-            Shape::Paper => 3 + opponent_hand.score as u64,
-            // win
-            Shape::Scissors => 6 + winners[&opponent_hand.shape].clone() as u64,
-        };
-        total_score += score;
-        // correct answer: 12989
+    // `--versus FILE` plays this guide against another one, reading both the same way
+    if let Some(other_path) = option("--versus")? {
+        let other_guide =
+            fs::read_to_string(other_path).expect("Should have been able to read the file");
+        let other_rounds = parse_strategy_guide(&other_guide, &rules)?;
+
+        let results = [
+            head_to_head(
+                &play(&rounds, &rules, &PlayShape)?,
+                &play(&other_rounds, &rules, &PlayShape)?,
+                &rules,
+            )?,
+            head_to_head(
+                &play(&rounds, &rules, &AchieveOutcome)?,
+                &play(&other_rounds, &rules, &AchieveOutcome)?,
+                &rules,
+            )?,
+        ];
+        for (part, result) in (1..).zip(results) {
+            println!(
+                "Part {part} head to head: {} wins, {} losses, {} draws, {} points to {}",
+                result.first_wins,
+                result.second_wins,
+                result.draws,
+                result.first_score,
+                result.second_score
+            );
+        }
     }
 
-    // 11319 is wrong
-    total_score
+    Ok(())
 }
@@ -0,0 +1,153 @@
+use anyhow::{bail, Result};
+
+use crate::{OpponentMove, Outcome, Rules};
+
+/// Highest total score possible against the opponent's moves, playing the best shape each round
+pub fn best_score(opponent: &[OpponentMove], rules: &Rules) -> u64 {
+    opponent
+        .iter()
+        .map(|theirs| round_scores(*theirs, rules).max().unwrap_or(0))
+        .sum()
+}
+
+/// Lowest total score possible against the opponent's moves
+pub fn worst_score(opponent: &[OpponentMove], rules: &Rules) -> u64 {
+    opponent
+        .iter()
+        .map(|theirs| round_scores(*theirs, rules).min().unwrap_or(0))
+        .sum()
+}
+
+/// The score of each shape against the opponent's in one round
+fn round_scores(theirs: OpponentMove, rules: &Rules) -> impl Iterator<Item = u64> + '_ {
+    (0..rules.shapes().len()).map(move |mine| rules.score(mine, theirs.0))
+}
+
+/// How likely each total score is when every response is picked uniformly at random
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoreDistribution {
+    /// Probability of each total score, indexed by the score
+    probabilities: Vec<f64>,
+}
+
+impl ScoreDistribution {
+    /// Works the distribution out exactly, one round at a time
+    pub fn random_responses(opponent: &[OpponentMove], rules: &Rules) -> Self {
+        let shapes = rules.shapes().len() as f64;
+        let mut probabilities = vec![1.0];
+        for theirs in opponent {
+            let scores = round_scores(*theirs, rules).collect::<Vec<u64>>();
+            let max_score = scores.iter().copied().max().unwrap_or(0) as usize;
+
+            let mut next = vec![0.0; probabilities.len() + max_score];
+            for (total, &p) in probabilities.iter().enumerate().filter(|(_, &p)| p > 0.0) {
+                for &score in &scores {
+                    next[total + score as usize] += p / shapes;
+                }
+            }
+            probabilities = next;
+        }
+
+        Self { probabilities }
+    }
+
+    pub fn probability(&self, score: u64) -> f64 {
+        self.probabilities.get(score as usize).copied().unwrap_or(0.0)
+    }
+
+    /// Probability of scoring `score` or less
+    pub fn at_most(&self, score: u64) -> f64 {
+        self.probabilities.iter().take(score as usize + 1).sum()
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.probabilities
+            .iter()
+            .enumerate()
+            .map(|(score, p)| score as f64 * p)
+            .sum()
+    }
+}
+
+/// The result of two players following their own guides against each other, round for round
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct HeadToHead {
+    pub first_wins: usize,
+    pub second_wins: usize,
+    pub draws: usize,
+    pub first_score: u64,
+    pub second_score: u64,
+}
+
+/// Plays the shapes chosen by two guides, as returned by `play`, against each other
+pub fn head_to_head(first: &[usize], second: &[usize], rules: &Rules) -> Result<HeadToHead> {
+    if first.len() != second.len() {
+        bail!(
+            "The guides have {} and {} rounds, they need the same number",
+            first.len(),
+            second.len()
+        );
+    }
+
+    let mut result = HeadToHead::default();
+    for (&a, &b) in first.iter().zip(second) {
+        match rules.outcome(a, b) {
+            Outcome::Win => result.first_wins += 1,
+            Outcome::Lose => result.second_wins += 1,
+            Outcome::Draw => result.draws += 1,
+        }
+        result.first_score += rules.score(a, b);
+        result.second_score += rules.score(b, a);
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_strategy_guide, play, AchieveOutcome, PlayShape};
+
+    #[test]
+    fn test_best_and_worst() {
+        let rules = Rules::classic();
+        let rounds = parse_strategy_guide(include_str!("../example1.txt"), &rules).unwrap();
+        let opponent = rounds.iter().map(|round| round.opponent).collect::<Vec<OpponentMove>>();
+
+        assert_eq!(best_score(&opponent, &rules), 8 + 9 + 7);
+        assert_eq!(worst_score(&opponent, &rules), 3 + 1 + 2);
+    }
+
+    #[test]
+    fn test_random_distribution() {
+        let rules = Rules::classic();
+        let opponent = [OpponentMove(0), OpponentMove(1), OpponentMove(2)];
+        let distribution = ScoreDistribution::random_responses(&opponent, &rules);
+
+        assert!((distribution.mean() - 15.0).abs() < 1e-9);
+        assert!((distribution.at_most(24) - 1.0).abs() < 1e-9);
+        assert!((distribution.probability(24) - 1.0 / 27.0).abs() < 1e-9);
+        assert!((distribution.probability(6) - 1.0 / 27.0).abs() < 1e-9);
+        assert_eq!(distribution.at_most(5), 0.0);
+        assert_eq!(ScoreDistribution::random_responses(&[], &rules).probability(0), 1.0);
+    }
+
+    #[test]
+    fn test_head_to_head() {
+        let rules = Rules::classic();
+        let rounds = parse_strategy_guide(include_str!("../example1.txt"), &rules).unwrap();
+        let shapes = play(&rounds, &rules, &PlayShape).unwrap();
+        let outcomes = play(&rounds, &rules, &AchieveOutcome).unwrap();
+
+        assert_eq!(
+            head_to_head(&shapes, &outcomes, &rules).unwrap(),
+            HeadToHead {
+                first_wins: 1,
+                second_wins: 1,
+                draws: 1,
+                first_score: 15,
+                second_score: 12,
+            }
+        );
+        assert!(head_to_head(&shapes, &outcomes[1..], &rules).is_err());
+    }
+}
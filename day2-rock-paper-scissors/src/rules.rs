@@ -0,0 +1,230 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl FromStr for Outcome {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "lose" => Ok(Outcome::Lose),
+            "draw" => Ok(Outcome::Draw),
+            "win" => Ok(Outcome::Win),
+            _ => bail!("Unknown outcome {s:?}, expected lose, draw or win"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shape {
+    pub name: String,
+    /// Symbol for the shape in the opponent's column of a strategy guide
+    pub opponent_symbol: String,
+    /// Symbol for the shape in the response column when it says which shape to play
+    pub response_symbol: String,
+    pub score: u64,
+}
+
+/// A cyclic hand game with an odd number of shapes. Shapes are identified by their index in
+/// `shapes()`, and each beats the half of the other shapes listed just before it, wrapping
+/// around, so every shape beats and loses to the same number of others.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    shapes: Vec<Shape>,
+    /// Response symbol and score of each outcome, indexed by `Outcome as usize`
+    outcomes: [(String, u64); 3],
+}
+
+impl FromStr for Rules {
+    type Err = anyhow::Error;
+
+    /// Parses `outcome <lose|draw|win> <response symbol> <score>` and
+    /// `shape <name> <opponent symbol> <response symbol> <score>` lines, in any order apart
+    /// from the shapes, which are listed in cycle order. Blank lines and lines starting with `#`
+    /// are ignored.
+    fn from_str(rules: &str) -> Result<Self> {
+        let mut shapes = Vec::new();
+        let mut outcomes: [Option<(String, u64)>; 3] = Default::default();
+        for (i, line) in rules.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields = line.split_whitespace().collect::<Vec<&str>>();
+            let parse_score = |score: &str| {
+                score
+                    .parse::<u64>()
+                    .with_context(|| format!("Invalid score {score:?} on line {}", i + 1))
+            };
+            match fields[..] {
+                ["outcome", outcome, symbol, score] => {
+                    let outcome = outcome
+                        .parse::<Outcome>()
+                        .with_context(|| format!("Invalid outcome on line {}", i + 1))?;
+                    if outcomes[outcome as usize].is_some() {
+                        bail!("Outcome {outcome:?} is defined twice, again on line {}", i + 1);
+                    }
+                    outcomes[outcome as usize] = Some((symbol.to_string(), parse_score(score)?));
+                }
+                ["shape", name, opponent_symbol, response_symbol, score] => shapes.push(Shape {
+                    name: name.to_string(),
+                    opponent_symbol: opponent_symbol.to_string(),
+                    response_symbol: response_symbol.to_string(),
+                    score: parse_score(score)?,
+                }),
+                _ => bail!("Unexpected rule {line:?} on line {}", i + 1),
+            }
+        }
+
+        if shapes.len() < 3 || shapes.len() % 2 == 0 {
+            bail!("Expected an odd number of shapes, at least 3, but found {}", shapes.len());
+        }
+        for symbols in [
+            shapes.iter().map(|shape| &shape.name).collect::<Vec<&String>>(),
+            shapes.iter().map(|shape| &shape.opponent_symbol).collect(),
+            shapes.iter().map(|shape| &shape.response_symbol).collect(),
+        ] {
+            if let Some(repeated) = symbols.iter().duplicates().next() {
+                bail!("{repeated:?} is used by more than one shape");
+            }
+        }
+
+        let [Some(lose), Some(draw), Some(win)] = outcomes else {
+            bail!("Expected a lose, draw and win outcome");
+        };
+        if [&lose.0, &draw.0, &win.0].iter().collect::<HashSet<_>>().len() != 3 {
+            bail!("Each outcome needs its own response symbol");
+        }
+
+        Ok(Self {
+            shapes,
+            outcomes: [lose, draw, win],
+        })
+    }
+}
+
+impl Rules {
+    /// Rock, paper, scissors as the puzzle describes it
+    pub fn classic() -> Self {
+        include_str!("../rules/rock-paper-scissors.txt")
+            .parse()
+            .expect("the classic rules are valid")
+    }
+
+    pub fn shapes(&self) -> &[Shape] {
+        &self.shapes
+    }
+
+    pub fn opponent_shape(&self, symbol: &str) -> Option<usize> {
+        self.shapes.iter().position(|shape| shape.opponent_symbol == symbol)
+    }
+
+    pub fn response_shape(&self, symbol: &str) -> Option<usize> {
+        self.shapes.iter().position(|shape| shape.response_symbol == symbol)
+    }
+
+    pub fn response_outcome(&self, symbol: &str) -> Option<Outcome> {
+        [Outcome::Lose, Outcome::Draw, Outcome::Win]
+            .into_iter()
+            .find(|&outcome| self.outcomes[outcome as usize].0 == symbol)
+    }
+
+    /// How the round goes for whoever plays `mine` against `theirs`
+    pub fn outcome(&self, mine: usize, theirs: usize) -> Outcome {
+        let n = self.shapes.len();
+        match (mine + n - theirs) % n {
+            0 => Outcome::Draw,
+            steps if steps <= n / 2 => Outcome::Win,
+            _ => Outcome::Lose,
+        }
+    }
+
+    pub fn outcome_score(&self, outcome: Outcome) -> u64 {
+        self.outcomes[outcome as usize].1
+    }
+
+    /// Score for playing `mine` against `theirs`: the shape's score plus the outcome's
+    pub fn score(&self, mine: usize, theirs: usize) -> u64 {
+        self.shapes[mine].score + self.outcome_score(self.outcome(mine, theirs))
+    }
+
+    /// The shape to play against `theirs` to get `outcome`. When several shapes would, the one
+    /// scoring the most is picked.
+    pub fn shape_for(&self, theirs: usize, outcome: Outcome) -> usize {
+        (0..self.shapes.len())
+            .filter(|&mine| self.outcome(mine, theirs) == outcome)
+            .max_by_key(|&mine| (self.shapes[mine].score, std::cmp::Reverse(mine)))
+            .expect("every outcome is possible against every shape")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classic_rules() {
+        let rules = Rules::classic();
+        let [rock, paper, scissors] = [0, 1, 2];
+
+        assert_eq!(rules.outcome(paper, rock), Outcome::Win);
+        assert_eq!(rules.outcome(rock, scissors), Outcome::Win);
+        assert_eq!(rules.outcome(rock, paper), Outcome::Lose);
+        assert_eq!(rules.outcome(scissors, scissors), Outcome::Draw);
+        assert_eq!(rules.score(paper, rock), 8);
+        assert_eq!(rules.shape_for(rock, Outcome::Lose), scissors);
+        assert_eq!(rules.response_outcome("Z"), Some(Outcome::Win));
+        assert_eq!(rules.opponent_shape("D"), None);
+    }
+
+    #[test]
+    fn test_lizard_spock() {
+        let rules = include_str!("../rules/rock-paper-scissors-lizard-spock.txt")
+            .parse::<Rules>()
+            .unwrap();
+        let shape = |name| rules.shapes().iter().position(|shape| shape.name == name).unwrap();
+
+        for (winner, loser) in [
+            ("Scissors", "Paper"),
+            ("Paper", "Rock"),
+            ("Rock", "Lizard"),
+            ("Lizard", "Spock"),
+            ("Spock", "Scissors"),
+            ("Scissors", "Lizard"),
+            ("Lizard", "Paper"),
+            ("Paper", "Spock"),
+            ("Spock", "Rock"),
+            ("Rock", "Scissors"),
+        ] {
+            assert_eq!(rules.outcome(shape(winner), shape(loser)), Outcome::Win);
+            assert_eq!(rules.outcome(shape(loser), shape(winner)), Outcome::Lose);
+        }
+        // Rock loses to Spock and Paper, Paper scores more
+        assert_eq!(rules.shape_for(shape("Rock"), Outcome::Win), shape("Paper"));
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        let outcomes = "outcome lose X 0\noutcome draw Y 3\noutcome win Z 6\n";
+
+        assert!(format!("{outcomes}shape R A X 1\nshape P B Y 2\n").parse::<Rules>().is_err());
+        assert!(format!("{outcomes}shape R A X 1\nshape P B Y 2\nshape S B Z 3\n")
+            .parse::<Rules>()
+            .is_err());
+        assert!("outcome lose X 0\nshape R A X 1\nshape P B Y 2\nshape S C Z 3\n"
+            .parse::<Rules>()
+            .is_err());
+        assert!(format!("{outcomes}shape R A X one\n").parse::<Rules>().is_err());
+        assert!(format!("{outcomes}outcome tie W 1\n").parse::<Rules>().is_err());
+    }
+}
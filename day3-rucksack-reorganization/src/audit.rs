@@ -0,0 +1,175 @@
+use std::fmt::Write;
+use std::ops::RangeInclusive;
+
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+
+use crate::{Alphabet, ItemSet, Rucksack};
+
+/// The item type that was packed into every compartment of a rucksack
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MisplacedItem {
+    /// Line of the rucksack in the input, starting at 1
+    pub line: usize,
+    pub item: char,
+    pub priority: u32,
+    /// Where the item is in each compartment, counting from 1 at the start of the compartment
+    pub positions: Vec<Vec<usize>>,
+}
+
+/// The item type every rucksack in a group has in common
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Badge {
+    /// Lines of the group's rucksacks in the input, starting at 1
+    pub lines: RangeInclusive<usize>,
+    pub item: char,
+    pub priority: u32,
+}
+
+/// The priority of the only item in `items`, or an error saying what's there instead
+fn only_item(items: ItemSet, alphabet: &Alphabet) -> Result<u32> {
+    let mut priorities = items.priorities();
+    match (priorities.next(), priorities.next()) {
+        (Some(priority), None) => Ok(priority),
+        (None, _) => bail!("No item type is common to all of them"),
+        _ => bail!(
+            "More than one item type is common to all of them: {}",
+            items.priorities().filter_map(|priority| alphabet.item(priority)).join(", ")
+        ),
+    }
+}
+
+/// Finds the item type in every compartment of each rucksack. There has to be exactly one.
+pub fn misplaced_items(
+    rucksacks: &[Rucksack],
+    alphabet: &Alphabet,
+) -> Result<Vec<MisplacedItem>> {
+    rucksacks
+        .iter()
+        .enumerate()
+        .map(|(i, rucksack)| {
+            let priority = only_item(rucksack.shared_items(), alphabet)
+                .with_context(|| format!("Compartments of the rucksack on line {}", i + 1))?;
+            let positions = rucksack
+                .compartment_items()
+                .map(|compartment| {
+                    (1..)
+                        .zip(compartment)
+                        .filter(|&(_, &item)| item == priority)
+                        .map(|(position, _)| position)
+                        .collect()
+                })
+                .collect();
+
+            Ok(MisplacedItem {
+                line: i + 1,
+                item: alphabet.item(priority).expect("parsed items are in the alphabet"),
+                priority,
+                positions,
+            })
+        })
+        .collect()
+}
+
+/// Finds the badge of each group of `group_size` elves, the one item type all their rucksacks
+/// have in common.
+pub fn badges(
+    rucksacks: &[Rucksack],
+    group_size: usize,
+    alphabet: &Alphabet,
+) -> Result<Vec<Badge>> {
+    if group_size == 0 || !rucksacks.len().is_multiple_of(group_size) {
+        bail!("{} rucksacks can't be split into groups of {group_size}", rucksacks.len());
+    }
+
+    rucksacks
+        .chunks(group_size)
+        .enumerate()
+        .map(|(group, elves)| {
+            let first_line = group * group_size + 1;
+            let lines = first_line..=first_line + group_size - 1;
+            let common = elves
+                .iter()
+                .map(Rucksack::item_set)
+                .reduce(|accum, rucksack| accum & rucksack)
+                .unwrap_or_default();
+            let priority = only_item(common, alphabet).with_context(|| {
+                format!("Group of rucksacks on lines {} to {}", lines.start(), lines.end())
+            })?;
+
+            Ok(Badge {
+                lines,
+                item: alphabet.item(priority).expect("parsed items are in the alphabet"),
+                priority,
+            })
+        })
+        .collect()
+}
+
+/// Lists every misplaced item with where to find it, then every group's badge
+pub fn report(misplaced_items: &[MisplacedItem], badges: &[Badge]) -> String {
+    let mut report = String::from("Misplaced items:\n");
+    for misplaced in misplaced_items {
+        let positions = (1..)
+            .zip(&misplaced.positions)
+            .map(|(compartment, positions)| {
+                format!("compartment {compartment} at {}", positions.iter().join(", "))
+            })
+            .join("; ");
+        let _ = writeln!(
+            report,
+            "  line {}: {} (priority {}) in {positions}",
+            misplaced.line, misplaced.item, misplaced.priority
+        );
+    }
+
+    report.push_str("Badges:\n");
+    for badge in badges {
+        let _ = writeln!(
+            report,
+            "  lines {} to {}: {} (priority {})",
+            badge.lines.start(),
+            badge.lines.end(),
+            badge.item,
+            badge.priority
+        );
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_rucksacks;
+
+    #[test]
+    fn test_report() {
+        let alphabet = Alphabet::default();
+        let rucksacks = parse_rucksacks(include_str!("../example.txt"), &alphabet, 2).unwrap();
+
+        let misplaced = misplaced_items(&rucksacks, &alphabet).unwrap();
+        assert_eq!(
+            misplaced[0],
+            MisplacedItem {
+                line: 1,
+                item: 'p',
+                priority: 16,
+                positions: vec![vec![5], vec![12]],
+            }
+        );
+        let badges = badges(&rucksacks, 3, &alphabet).unwrap();
+        assert_eq!(badges[1].lines, 4..=6);
+        assert_eq!(badges[1].item, 'Z');
+
+        let report = report(&misplaced, &badges);
+        assert!(report.starts_with(
+            "Misplaced items:\n  line 1: p (priority 16) in compartment 1 at 5; compartment 2 at 12\n"
+        ));
+        assert!(report.contains(
+            "  line 2: L (priority 38) in compartment 1 at 14, 16; compartment 2 at 10, 16\n"
+        ));
+        assert!(report.ends_with(
+            "Badges:\n  lines 1 to 3: r (priority 18)\n  lines 4 to 6: Z (priority 52)\n"
+        ));
+    }
+}
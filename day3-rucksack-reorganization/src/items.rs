@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+use std::ops::{BitAnd, BitOr};
+
+use anyhow::{bail, Context, Result};
+
+/// The item types that can be packed, in priority order: the first has priority 1, the next 2
+/// and so on. There can be at most 64, so any set of them fits in an `ItemSet`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alphabet {
+    items: Vec<char>,
+    priorities: HashMap<char, u32>,
+}
+
+impl Alphabet {
+    pub fn new(items: &str) -> Result<Self> {
+        let items = items.chars().collect::<Vec<char>>();
+        if items.is_empty() || items.len() > 64 {
+            bail!("An alphabet needs between 1 and 64 item types, not {}", items.len());
+        }
+
+        let mut priorities = HashMap::new();
+        for (priority, &item) in (1..).zip(&items) {
+            if priorities.insert(item, priority).is_some() {
+                bail!("Item type {item:?} appears more than once in the alphabet");
+            }
+        }
+        Ok(Self { items, priorities })
+    }
+
+    pub fn priority(&self, item: char) -> Result<u32> {
+        self.priorities
+            .get(&item)
+            .copied()
+            .with_context(|| format!("Unknown item type {item:?}"))
+    }
+
+    pub fn item(&self, priority: u32) -> Option<char> {
+        self.items.get((priority as usize).checked_sub(1)?).copied()
+    }
+}
+
+impl Default for Alphabet {
+    /// The puzzle's alphabet: a through z have priorities 1 through 26, A through Z 27 through 52
+    fn default() -> Self {
+        Self::new("abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ").unwrap()
+    }
+}
+
+/// A set of item types, held as a bitmask with bit `priority - 1` set for each item
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    /// Every item with a priority from 1 up to and including `max_priority`
+    pub fn all(max_priority: u32) -> Self {
+        Self(u64::MAX >> (64 - max_priority.clamp(1, 64)))
+    }
+
+    /// Adds the item with `priority`, which has to be from 1 to 64 as [`Alphabet`] makes sure of
+    pub fn insert(&mut self, priority: u32) {
+        assert!((1..=64).contains(&priority), "Item priority {priority} doesn't fit in an ItemSet");
+        self.0 |= 1 << (priority - 1);
+    }
+
+    pub fn contains(self, priority: u32) -> bool {
+        (1..=64).contains(&priority) && self.0 & (1 << (priority - 1)) != 0
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn len(self) -> u32 {
+        self.0.count_ones()
+    }
+
+    /// The priorities of the items in the set, lowest first
+    pub fn priorities(self) -> impl Iterator<Item = u32> {
+        let mut bits = self.0;
+        std::iter::from_fn(move || {
+            let bit = (bits != 0).then(|| bits.trailing_zeros())?;
+            bits &= bits - 1;
+            Some(bit + 1)
+        })
+    }
+}
+
+impl FromIterator<u32> for ItemSet {
+    fn from_iter<I: IntoIterator<Item = u32>>(priorities: I) -> Self {
+        let mut set = Self::default();
+        for priority in priorities {
+            set.insert(priority);
+        }
+        set
+    }
+}
+
+impl BitAnd for ItemSet {
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+}
+
+impl BitOr for ItemSet {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alphabet() {
+        let alphabet = Alphabet::default();
+
+        assert_eq!(alphabet.priority('p').unwrap(), 16);
+        assert_eq!(alphabet.priority('L').unwrap(), 38);
+        assert_eq!(alphabet.item(52), Some('Z'));
+        assert_eq!(alphabet.item(0), None);
+        assert!(alphabet.priority('1').is_err());
+        assert!(alphabet.priority('é').is_err());
+
+        let digits = Alphabet::new("0123456789").unwrap();
+        assert_eq!(digits.priority('0').unwrap(), 1);
+        assert!(Alphabet::new("abca").is_err());
+        assert!(Alphabet::new("").is_err());
+        assert!(Alphabet::new(&('\u{100}'..='\u{140}').collect::<String>()).is_err());
+    }
+
+    #[test]
+    fn test_item_set() {
+        let a = [1, 3, 64].into_iter().collect::<ItemSet>();
+        let b = [3, 4].into_iter().collect::<ItemSet>();
+
+        assert_eq!((a & b).priorities().collect::<Vec<u32>>(), vec![3]);
+        assert_eq!((a | b).len(), 4);
+        assert!(a.contains(64));
+        assert!(!a.contains(65));
+        assert!((a & ItemSet::default()).is_empty());
+        assert_eq!(ItemSet::all(64).len(), 64);
+        assert_eq!(ItemSet::all(3), [1, 2, 3].into_iter().collect());
+    }
+
+    #[test]
+    #[should_panic(expected = "Item priority 0 doesn't fit in an ItemSet")]
+    fn test_item_set_rejects_priority_0() {
+        ItemSet::default().insert(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Item priority 65 doesn't fit in an ItemSet")]
+    fn test_item_set_rejects_priority_65() {
+        ItemSet::default().insert(65);
+    }
+}
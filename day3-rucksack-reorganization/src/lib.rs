@@ -0,0 +1,156 @@
+use anyhow::{bail, Context, Result};
+
+pub mod audit;
+pub mod items;
+pub mod reorganize;
+
+pub use audit::{badges, misplaced_items};
+pub use items::{Alphabet, ItemSet};
+
+/// A rucksack's items by priority, in the order they're packed, split into equally sized
+/// compartments
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rucksack {
+    items: Vec<u32>,
+    compartments: usize,
+}
+
+impl Rucksack {
+    pub fn items(&self) -> &[u32] {
+        &self.items
+    }
+
+    /// The items in each compartment, in packing order
+    pub fn compartment_items(&self) -> impl Iterator<Item = &[u32]> {
+        let size = self.items.len() / self.compartments;
+        (0..self.compartments).map(move |c| &self.items[c * size..(c + 1) * size])
+    }
+
+    /// The item types in each compartment
+    pub fn compartments(&self) -> Vec<ItemSet> {
+        self.compartment_items()
+            .map(|compartment| compartment.iter().copied().collect())
+            .collect()
+    }
+
+    /// The item types found in every compartment
+    pub fn shared_items(&self) -> ItemSet {
+        self.compartments()
+            .into_iter()
+            .reduce(|accum, compartment| accum & compartment)
+            .unwrap_or_default()
+    }
+
+    /// Every item type in the rucksack
+    pub fn item_set(&self) -> ItemSet {
+        self.items.iter().copied().collect()
+    }
+}
+
+/// Parses one rucksack per line, checking that every item is in `alphabet` and that each
+/// rucksack can be split into `compartments` equally sized compartments.
+pub fn parse_rucksacks(
+    rucksacks: &str,
+    alphabet: &Alphabet,
+    compartments: usize,
+) -> Result<Vec<Rucksack>> {
+    if compartments == 0 {
+        bail!("Rucksacks need at least one compartment");
+    }
+
+    rucksacks
+        .lines()
+        .enumerate()
+        .map(|(i, rucksack)| {
+            let items = rucksack
+                .chars()
+                .map(|item| alphabet.priority(item))
+                .collect::<Result<Vec<u32>>>()
+                .with_context(|| format!("Rucksack on line {} contains an unknown item", i + 1))?;
+            if !items.len().is_multiple_of(compartments) {
+                bail!(
+                    "Rucksack on line {} can't be split into {compartments} equal compartments",
+                    i + 1
+                );
+            }
+            Ok(Rucksack { items, compartments })
+        })
+        .collect()
+}
+
+/// Sums the priority of the item type in every compartment of each rucksack. There has to be
+/// exactly one.
+pub fn part_1(rucksacks: &[Rucksack], alphabet: &Alphabet) -> Result<u32> {
+    Ok(misplaced_items(rucksacks, alphabet)?.iter().map(|misplaced| misplaced.priority).sum())
+}
+
+/// Sums the priority of the badge of each group of `group_size` elves, the one item type all
+/// their rucksacks have in common.
+pub fn part_2(rucksacks: &[Rucksack], group_size: usize, alphabet: &Alphabet) -> Result<u32> {
+    Ok(badges(rucksacks, group_size, alphabet)?.iter().map(|badge| badge.priority).sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rucksacks() {
+        let input = include_str!("../example.txt");
+        let alphabet = Alphabet::default();
+
+        let rucksacks = parse_rucksacks(input, &alphabet, 2).unwrap();
+        assert_eq!(rucksacks.len(), 6);
+        assert_eq!(rucksacks[0].items()[..3], [22, 36, 18]);
+        assert!(parse_rucksacks("vJrwpWtwJgWrhcsFMMfFFhF", &alphabet, 2).is_err());
+        assert!(parse_rucksacks("vJrwpWtw1gWrhcsFMMfFFhFp", &alphabet, 2).is_err());
+        assert!(parse_rucksacks("abcdef", &Alphabet::new("abc").unwrap(), 2).is_err());
+    }
+
+    #[test]
+    fn test_parts() {
+        let input = include_str!("../example.txt");
+        let alphabet = Alphabet::default();
+        let rucksacks = parse_rucksacks(input, &alphabet, 2).unwrap();
+
+        assert_eq!(part_1(&rucksacks, &alphabet).unwrap(), 157);
+        assert_eq!(part_2(&rucksacks, 3, &alphabet).unwrap(), 70);
+    }
+
+    #[test]
+    fn test_custom_alphabet() {
+        let alphabet = Alphabet::new("0123456789").unwrap();
+        let rucksacks = parse_rucksacks("1231\n4144\n167812", &alphabet, 2).unwrap();
+
+        assert_eq!(part_1(&rucksacks, &alphabet).unwrap(), 2 + 5 + 2);
+        assert_eq!(part_2(&rucksacks, 3, &alphabet).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_compartments_and_group_size() {
+        let alphabet = Alphabet::default();
+        let rucksacks = parse_rucksacks("abcadeafg\nxayzaqaww", &alphabet, 3).unwrap();
+
+        assert_eq!(rucksacks[1].compartments().len(), 3);
+        assert_eq!(part_1(&rucksacks, &alphabet).unwrap(), 1 + 1);
+        assert_eq!(part_2(&rucksacks, 2, &alphabet).unwrap(), 1);
+        assert!(parse_rucksacks("abcd", &alphabet, 3).is_err());
+        assert!(parse_rucksacks("abc", &alphabet, 0).is_err());
+    }
+
+    #[test]
+    fn test_no_single_common_item() {
+        let alphabet = Alphabet::default();
+        let example = parse_rucksacks(include_str!("../example.txt"), &alphabet, 2).unwrap();
+
+        let e = part_1(&parse_rucksacks("abab", &alphabet, 2).unwrap(), &alphabet).unwrap_err();
+        assert_eq!(
+            format!("{e:#}"),
+            "Compartments of the rucksack on line 1: More than one item type is common to all of them: a, b"
+        );
+        assert!(part_1(&parse_rucksacks("abcd", &alphabet, 2).unwrap(), &alphabet).is_err());
+        assert!(part_2(&example, 2, &alphabet).is_err());
+        assert!(part_2(&example, 4, &alphabet).is_err());
+        assert!(part_2(&example, 0, &alphabet).is_err());
+    }
+}
@@ -1,73 +1,72 @@
-use std::collections::HashSet;
 use std::env;
 use std::fs;
 
+use anyhow::{Context, Result};
+use day3_rucksack_reorganization::audit::report;
+use day3_rucksack_reorganization::reorganize::{format_rucksacks, reorganize};
+use day3_rucksack_reorganization::{
+    badges, misplaced_items, parse_rucksacks, part_1, part_2, Alphabet,
+};
 use itertools::Itertools;
 
-trait Priority {
-    const PRIORITY_LOWERCASE_A: u32 = 1;
-    const PRIORITY_UPPERCASE_A: u32 = 27;
-    fn priority(self) -> u32;
-}
-
-impl Priority for char {
-    fn priority(self) -> u32 {
-        if self.is_lowercase() {
-            self as u32 - 'a' as u32 + <char as Priority>::PRIORITY_LOWERCASE_A
-        } else {
-            self as u32 - 'A' as u32 + <char as Priority>::PRIORITY_UPPERCASE_A
-        }
-    }
-}
-
-fn main() {
+fn main() -> Result<()> {
     let args: Vec<String> = env::args().collect();
     let file_path = &args[1];
 
     let rucksacks = fs::read_to_string(file_path).expect("Should have been able to read the file");
+    let option = |name: &str| {
+        let idx = args.iter().position(|arg| arg == name)?;
+        args.get(idx + 1).map(String::as_str)
+    };
 
-    part_1(&rucksacks);
-    part_2(&rucksacks);
-}
-
-fn part_1(rucksacks: &str) {
-    let mut shared_items = Vec::new();
-    for rucksack in rucksacks.lines() {
-        let (compartment_1, compartment_2) = &rucksack.split_at(rucksack.len() / 2);
+    // `--alphabet ITEMS` lists the item types in priority order, instead of a-z then A-Z
+    let alphabet = match option("--alphabet") {
+        Some(items) => Alphabet::new(items)?,
+        None => Alphabet::default(),
+    };
+    let compartments = option("--compartments").map_or(Ok(2), str::parse)?;
+    let group_size = option("--group-size").map_or(Ok(3), str::parse)?;
+    let rucksacks = parse_rucksacks(&rucksacks, &alphabet, compartments)?;
 
-        let mut compartment_1_set = HashSet::new();
-        let mut compartment_2_set = HashSet::new();
-        for (c1_item, c2_item) in compartment_1.chars().zip(compartment_2.chars()) {
-            compartment_1_set.insert(c1_item);
-            compartment_2_set.insert(c2_item);
-
-            if compartment_1_set.contains(&c2_item) {
-                shared_items.push(c2_item.priority());
-                break;
-            }
+    println!("The sum of the priorities of the item types that appear in both compartments of each rucksack is: {}",
+        part_1(&rucksacks, &alphabet)?
+    );
+    println!("The sum of the priorities of the item type that corresponds to the badges of each {group_size}-Elf group is: {}",
+        part_2(&rucksacks, group_size, &alphabet)?
+    );
 
-            if compartment_2_set.contains(&c1_item) {
-                shared_items.push(c1_item.priority());
-                break;
-            }
-        }
+    // `--audit` lists where each misplaced item and badge is, to reorganize the rucksacks by
+    if args.iter().any(|arg| arg == "--audit") {
+        print!(
+            "{}",
+            report(
+                &misplaced_items(&rucksacks, &alphabet)?,
+                &badges(&rucksacks, group_size, &alphabet)?
+            )
+        );
     }
-    println!("The sum of the priorities of the item types that appear in both compartments of each rucksack is: {}",
-        shared_items.iter().sum::<u32>()
-    )
-}
 
-fn part_2(rucksacks: &str) {
-    let mut badges = Vec::new();
-    for elves in &rucksacks.lines().chunks(3) {
-        let badge = elves
-            .map(|items_str| items_str.chars().collect::<HashSet<char>>())
-            .reduce(|accum, rucksack| &accum & &rucksack)
-            .unwrap();
+    // `--reorganize FILE` writes the rucksacks to FILE after the fewest item moves that leave
+    // every item type in a single compartment
+    if let Some(reorganized_path) = option("--reorganize") {
+        let plans = reorganize(&rucksacks, &alphabet)?;
+        for plan in plans.iter().filter(|plan| !plan.moves.is_empty()) {
+            let moves = plan
+                .moves
+                .iter()
+                .map(|item_move| {
+                    let item = alphabet.item(item_move.priority).unwrap_or('?');
+                    format!("{item} from {} to {}", item_move.from, item_move.to)
+                })
+                .join(", ");
+            println!("Line {}: move {moves}", plan.line);
+        }
+        println!("{} items moved", plans.iter().map(|plan| plan.moves.len()).sum::<usize>());
 
-        badges.push(badge.iter().next().unwrap().priority());
+        let reorganized = format_rucksacks(plans.iter().map(|plan| &plan.rucksack), &alphabet);
+        fs::write(reorganized_path, reorganized)
+            .with_context(|| format!("Could not write {reorganized_path}"))?;
     }
-    println!("The sum of the priorities of the item type that corresponds to the badges of each three-Elf group is: {}",
-        badges.iter().sum::<u32>()
-    )
+
+    Ok(())
 }
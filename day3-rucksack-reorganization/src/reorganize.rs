@@ -0,0 +1,217 @@
+use std::collections::{BTreeMap, HashMap};
+
+use anyhow::{bail, Result};
+use itertools::Itertools;
+
+use crate::{Alphabet, Rucksack};
+
+/// One item taken out of a compartment and packed into another. Compartments are numbered
+/// from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ItemMove {
+    pub priority: u32,
+    pub from: usize,
+    pub to: usize,
+}
+
+/// The fewest moves that leave no item type in more than one compartment of a rucksack
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    /// Line of the rucksack in the input, starting at 1
+    pub line: usize,
+    pub moves: Vec<ItemMove>,
+    /// The rucksack once the moves are made
+    pub rucksack: Rucksack,
+}
+
+/// Plans every rucksack, erroring on any that can't be reorganized at all because its item
+/// types can't be shared out into equally sized compartments.
+pub fn reorganize(rucksacks: &[Rucksack], alphabet: &Alphabet) -> Result<Vec<Plan>> {
+    rucksacks
+        .iter()
+        .enumerate()
+        .map(|(i, rucksack)| {
+            let Some((moves, rucksack)) = plan(rucksack) else {
+                bail!(
+                    "Rucksack on line {} can't have each item type in a single compartment: {}",
+                    i + 1,
+                    format_rucksack(rucksack, alphabet)
+                );
+            };
+            Ok(Plan {
+                line: i + 1,
+                moves,
+                rucksack,
+            })
+        })
+        .collect()
+}
+
+/// Every item type has to end up in a single compartment, so the plan picks a compartment for
+/// each type that keeps the compartments the same size and keeps the most items where they are.
+fn plan(rucksack: &Rucksack) -> Option<(Vec<ItemMove>, Rucksack)> {
+    let compartments = rucksack.compartments;
+    let size = rucksack.items.len() / compartments;
+    let compartment_of = |position: usize| position / size.max(1);
+
+    // how many of each item type are in each compartment
+    let mut counts = BTreeMap::<u32, Vec<usize>>::new();
+    for (position, &priority) in rucksack.items.iter().enumerate() {
+        let count = counts.entry(priority).or_insert_with(|| vec![0; compartments]);
+        count[compartment_of(position)] += 1;
+    }
+    let counts = counts.into_iter().collect::<Vec<(u32, Vec<usize>)>>();
+
+    let mut memo = HashMap::new();
+    fewest_moves(&counts, vec![size; compartments], &mut memo)?;
+
+    // follow the choices that led to the fewest moves
+    let mut assigned = HashMap::new();
+    let mut space = vec![size; compartments];
+    for (i, (priority, count)) in counts.iter().enumerate() {
+        let total = count.iter().sum::<usize>();
+        let target = (0..compartments)
+            .filter(|&c| space[c] >= total)
+            .min_by_key(|&c| {
+                let mut rest = space.clone();
+                rest[c] -= total;
+                let moves = fewest_moves(&counts[i + 1..], rest, &mut memo);
+                moves.map_or(usize::MAX, |moves| moves + total - count[c])
+            })?;
+        space[target] -= total;
+        assigned.insert(*priority, target);
+    }
+
+    let mut moves = Vec::new();
+    let mut incoming = vec![Vec::new(); compartments];
+    for (position, priority) in rucksack.items.iter().enumerate() {
+        let (from, to) = (compartment_of(position), assigned[priority]);
+        if from != to {
+            moves.push(ItemMove {
+                priority: *priority,
+                from: from + 1,
+                to: to + 1,
+            });
+            incoming[to].push(*priority);
+        }
+    }
+
+    // moved items fill the gaps left in their new compartment, everything else stays put
+    let mut incoming = incoming.into_iter().map(Vec::into_iter).collect::<Vec<_>>();
+    let items = rucksack
+        .items
+        .iter()
+        .enumerate()
+        .map(|(position, priority)| {
+            let compartment = compartment_of(position);
+            if assigned[priority] == compartment {
+                *priority
+            } else {
+                incoming[compartment].next().expect("compartments stay the same size")
+            }
+        })
+        .collect();
+
+    Some((moves, Rucksack { items, compartments }))
+}
+
+/// The fewest moves to fit the remaining item types, with `counts` per compartment, into the
+/// `space` left in each compartment, or `None` if they don't fit.
+fn fewest_moves(
+    counts: &[(u32, Vec<usize>)],
+    space: Vec<usize>,
+    memo: &mut HashMap<(usize, Vec<usize>), Option<usize>>,
+) -> Option<usize> {
+    let Some(((_, count), rest)) = counts.split_first() else {
+        return Some(0);
+    };
+    if let Some(&moves) = memo.get(&(counts.len(), space.clone())) {
+        return moves;
+    }
+
+    let total = count.iter().sum::<usize>();
+    let moves = (0..space.len())
+        .filter(|&c| space[c] >= total)
+        .filter_map(|c| {
+            let mut space = space.clone();
+            space[c] -= total;
+            Some(fewest_moves(rest, space, memo)? + total - count[c])
+        })
+        .min();
+    memo.insert((counts.len(), space), moves);
+    moves
+}
+
+/// Writes a rucksack back out in the input format
+pub fn format_rucksack(rucksack: &Rucksack, alphabet: &Alphabet) -> String {
+    rucksack
+        .items
+        .iter()
+        .map(|&priority| alphabet.item(priority).expect("parsed items are in the alphabet"))
+        .collect()
+}
+
+/// Writes every rucksack back out in the input format, one per line
+pub fn format_rucksacks<'a>(
+    rucksacks: impl IntoIterator<Item = &'a Rucksack>,
+    alphabet: &Alphabet,
+) -> String {
+    rucksacks
+        .into_iter()
+        .map(|rucksack| format_rucksack(rucksack, alphabet) + "\n")
+        .join("")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_rucksacks, part_1};
+
+    #[test]
+    fn test_reorganize_example() {
+        let input = include_str!("../example.txt");
+        let alphabet = Alphabet::default();
+        let rucksacks = parse_rucksacks(input, &alphabet, 2).unwrap();
+
+        let plans = reorganize(&rucksacks, &alphabet).unwrap();
+        // the shared p has to join the other p, and a type seen once swaps the other way
+        assert_eq!(plans[0].moves.len(), 2);
+        assert!(plans.iter().all(|plan| plan.rucksack.shared_items().is_empty()));
+
+        let reorganized = format_rucksacks(plans.iter().map(|plan| &plan.rucksack), &alphabet);
+        let reparsed = parse_rucksacks(&reorganized, &alphabet, 2).unwrap();
+        assert_eq!(reparsed.len(), 6);
+        for (before, after) in rucksacks.iter().zip(&reparsed) {
+            assert_eq!(before.item_set(), after.item_set());
+            assert_eq!(before.items().len(), after.items().len());
+        }
+        assert!(part_1(&reparsed, &alphabet).is_err());
+    }
+
+    #[test]
+    fn test_fewest_moves() {
+        let alphabet = Alphabet::default();
+        let rucksacks = parse_rucksacks("abcd\nabac\naaab", &alphabet, 2).unwrap();
+
+        let plans = reorganize(&rucksacks[..2], &alphabet).unwrap();
+        assert!(plans[0].moves.is_empty());
+        assert_eq!(format_rucksack(&plans[0].rucksack, &alphabet), "abcd");
+        // the a in the second compartment swaps with b
+        assert_eq!(
+            plans[1].moves,
+            vec![
+                ItemMove { priority: 2, from: 1, to: 2 },
+                ItemMove { priority: 1, from: 2, to: 1 },
+            ]
+        );
+        assert_eq!(format_rucksack(&plans[1].rucksack, &alphabet), "aabc");
+
+        assert!(reorganize(&rucksacks[2..3], &alphabet).is_err());
+
+        let four = parse_rucksacks("abbaccdd", &alphabet, 4).unwrap();
+        let plans = reorganize(&four, &alphabet).unwrap();
+        assert_eq!(plans[0].moves.len(), 2);
+        assert_eq!(plans[0].rucksack.compartments().len(), 4);
+        assert!(plans[0].rucksack.compartments().iter().all(|c| c.len() == 1));
+    }
+}
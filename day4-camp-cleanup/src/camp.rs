@@ -0,0 +1,65 @@
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use crate::IntervalSet;
+
+/// How the sections of the whole camp are shared out between the elves
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Coverage {
+    /// Sections assigned to at least one elf
+    pub covered: IntervalSet,
+    /// Sections between the lowest and highest assigned section that no elf was given
+    pub uncovered: IntervalSet,
+    /// Sections assigned to more than one elf
+    pub shared: IntervalSet,
+}
+
+impl Coverage {
+    pub fn new<'a>(assignments: impl IntoIterator<Item = &'a RangeInclusive<u32>>) -> Self {
+        let mut covered = IntervalSet::new();
+        let mut shared = IntervalSet::new();
+        for assignment in assignments {
+            let assignment = IntervalSet::merge([assignment.clone()]);
+            shared = shared.union(&covered.intersection(&assignment));
+            covered = covered.union(&assignment);
+        }
+        let uncovered = covered
+            .span()
+            .map(|span| IntervalSet::merge([span]).difference(&covered))
+            .unwrap_or_default();
+
+        Self { covered, uncovered, shared }
+    }
+}
+
+impl fmt::Display for Coverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Sections covered: {}", self.covered.len())?;
+        writeln!(f, "Sections covered by no elf: {}", self.uncovered.len())?;
+        write!(f, "Sections assigned to more than one elf: {}", self.shared.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_assignments;
+
+    #[test]
+    fn test_example_coverage() {
+        let groups = parse_assignments(include_str!("../example.txt")).unwrap();
+        let coverage = Coverage::new(groups.iter().flatten());
+
+        assert_eq!(coverage.covered.ranges(), &[2..=9]);
+        assert!(coverage.uncovered.is_empty());
+        assert_eq!(coverage.shared.ranges(), &[2..=8]);
+        assert_eq!(
+            coverage.to_string(),
+            "Sections covered: 8\nSections covered by no elf: 0\nSections assigned to more than one elf: 7"
+        );
+
+        let coverage = Coverage::new(&[1..=3, 7..=9, 2..=2, 12..=12]);
+        assert_eq!(coverage.uncovered.ranges(), &[4..=6, 10..=11]);
+        assert_eq!(coverage.shared.ranges(), &[2..=2]);
+    }
+}
@@ -0,0 +1,130 @@
+use std::fmt::Write;
+use std::ops::RangeInclusive;
+
+use itertools::Itertools;
+
+use crate::{fully_overlapping, overlapping, Group};
+
+/// How the assignments of one group of elves overlap each other
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupReport {
+    /// Line of the group in the input, starting at 1
+    pub line: usize,
+    pub elves: usize,
+    /// Pairs of elves in the group where one range fully contains the other
+    pub contained_pairs: usize,
+    /// Pairs of elves in the group whose ranges overlap at all
+    pub overlapping_pairs: usize,
+    /// The sections every elf in the group was assigned, if there are any
+    pub common: Option<RangeInclusive<u32>>,
+}
+
+impl GroupReport {
+    pub fn new(line: usize, group: &Group) -> Self {
+        let mut contained_pairs = 0;
+        let mut overlapping_pairs = 0;
+        for (x, y) in group.iter().tuple_combinations() {
+            if fully_overlapping(x, y) {
+                contained_pairs += 1;
+            }
+            if overlapping(x, y) {
+                overlapping_pairs += 1;
+            }
+        }
+
+        let common = group
+            .iter()
+            .cloned()
+            .reduce(|common, range| {
+                *common.start().max(range.start())..=*common.end().min(range.end())
+            })
+            .filter(|common| !common.is_empty());
+
+        Self {
+            line,
+            elves: group.len(),
+            contained_pairs,
+            overlapping_pairs,
+            common,
+        }
+    }
+
+    pub fn pairs(&self) -> usize {
+        self.elves * self.elves.saturating_sub(1) / 2
+    }
+
+    /// Whether any elf's range fully contains another's
+    pub fn any_containment(&self) -> bool {
+        self.contained_pairs > 0
+    }
+
+    /// Whether any two elves' ranges overlap
+    pub fn any_overlap(&self) -> bool {
+        self.overlapping_pairs > 0
+    }
+
+    /// Whether there's a section every elf in the group was assigned
+    pub fn all_overlap(&self) -> bool {
+        self.common.is_some()
+    }
+}
+
+/// Reports on every group, in the order they're listed
+pub fn reports(groups: &[Group]) -> Vec<GroupReport> {
+    (1..).zip(groups).map(|(line, group)| GroupReport::new(line, group)).collect()
+}
+
+/// Lists each group's report on its own line
+pub fn table(reports: &[GroupReport]) -> String {
+    let mut table = String::new();
+    for report in reports {
+        let common = match &report.common {
+            Some(common) => format!("sections {}-{} common to all", common.start(), common.end()),
+            None => String::from("no section common to all"),
+        };
+        let _ = writeln!(
+            table,
+            "line {}: {} elves, {} of {} pairs contained, {} of {} pairs overlapping, {common}",
+            report.line,
+            report.elves,
+            report.contained_pairs,
+            report.pairs(),
+            report.overlapping_pairs,
+            report.pairs()
+        );
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_assignments;
+
+    #[test]
+    fn test_group_reports() {
+        let groups = parse_assignments("2-8,3-7,4-6\n1-3,3-5,5-7\n1-1").unwrap();
+        let reports = reports(&groups);
+
+        assert_eq!(
+            reports[0],
+            GroupReport {
+                line: 1,
+                elves: 3,
+                contained_pairs: 3,
+                overlapping_pairs: 3,
+                common: Some(4..=6),
+            }
+        );
+        assert!(!reports[1].any_containment());
+        assert_eq!(reports[1].overlapping_pairs, 2);
+        assert!(reports[1].any_overlap() && !reports[1].all_overlap());
+        assert_eq!(reports[2].pairs(), 0);
+        assert!(reports[2].all_overlap() && !reports[2].any_overlap());
+
+        assert_eq!(
+            table(&reports[1..2]),
+            "line 2: 3 elves, 0 of 3 pairs contained, 2 of 3 pairs overlapping, no section common to all\n"
+        );
+    }
+}
@@ -0,0 +1,164 @@
+use std::ops::RangeInclusive;
+
+/// A set of section IDs, held as sorted ranges that neither overlap nor touch
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct IntervalSet {
+    ranges: Vec<RangeInclusive<u32>>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds the set of every section in any of `ranges`, joining ranges that overlap or touch.
+    /// Empty ranges are ignored.
+    pub fn merge(ranges: impl IntoIterator<Item = RangeInclusive<u32>>) -> Self {
+        let mut ranges = ranges
+            .into_iter()
+            .filter(|range| !range.is_empty())
+            .collect::<Vec<RangeInclusive<u32>>>();
+        ranges.sort_unstable_by_key(|range| *range.start());
+
+        let mut merged: Vec<RangeInclusive<u32>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if *range.start() <= last.end().saturating_add(1) => {
+                    *last = *last.start()..=*last.end().max(range.end());
+                }
+                _ => merged.push(range),
+            }
+        }
+        Self { ranges: merged }
+    }
+
+    pub fn ranges(&self) -> &[RangeInclusive<u32>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Number of sections in the set
+    pub fn len(&self) -> u64 {
+        self.ranges
+            .iter()
+            .map(|range| (range.end() - range.start()) as u64 + 1)
+            .sum()
+    }
+
+    pub fn contains(&self, section: u32) -> bool {
+        let idx = self.ranges.partition_point(|range| *range.end() < section);
+        self.ranges.get(idx).is_some_and(|range| range.contains(&section))
+    }
+
+    /// The smallest range holding every section in the set
+    pub fn span(&self) -> Option<RangeInclusive<u32>> {
+        Some(*self.ranges.first()?.start()..=*self.ranges.last()?.end())
+    }
+
+    pub fn insert(&mut self, range: RangeInclusive<u32>) {
+        *self = self.union(&Self::merge([range]));
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::merge(self.ranges.iter().chain(&other.ranges).cloned())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let start = *a.start().max(b.start());
+            let end = *a.end().min(b.end());
+            if start <= end {
+                ranges.push(start..=end);
+            }
+            // whichever range ends first can't meet anything else
+            if a.end() < b.end() {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    /// The sections in this set that aren't in `other`
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let mut others = other.ranges.iter().peekable();
+        for range in &self.ranges {
+            let mut start = *range.start();
+            let end = *range.end();
+            let mut remaining = true;
+            while let Some(cut) = others.peek() {
+                if cut.end() < &start {
+                    others.next();
+                    continue;
+                }
+                if cut.start() > &end {
+                    break;
+                }
+                if cut.start() > &start {
+                    ranges.push(start..=cut.start() - 1);
+                }
+                if *cut.end() >= end {
+                    remaining = false;
+                    break;
+                }
+                start = cut.end() + 1;
+                others.next();
+            }
+            if remaining {
+                ranges.push(start..=end);
+            }
+        }
+        Self { ranges }
+    }
+}
+
+impl FromIterator<RangeInclusive<u32>> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<u32>>>(ranges: I) -> Self {
+        Self::merge(ranges)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge() {
+        let empty = RangeInclusive::new(12, 11);
+        let set = IntervalSet::merge([5..=7, 1..=2, 3..=3, 6..=9, empty, 20..=u32::MAX]);
+
+        assert_eq!(set.ranges(), &[1..=3, 5..=9, 20..=u32::MAX]);
+        assert_eq!(set.len(), 3 + 5 + (u32::MAX - 20) as u64 + 1);
+        assert!(set.contains(9));
+        assert!(!set.contains(4));
+        assert!(!set.contains(0));
+        assert_eq!(set.span(), Some(1..=u32::MAX));
+        assert_eq!(IntervalSet::new().span(), None);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = IntervalSet::merge([1..=5, 10..=15, 20..=25]);
+        let b = IntervalSet::merge([4..=11, 15..=20, 30..=31]);
+
+        assert_eq!(a.union(&b).ranges(), &[1..=25, 30..=31]);
+        assert_eq!(a.intersection(&b).ranges(), &[4..=5, 10..=11, 15..=15, 20..=20]);
+        assert_eq!(a.difference(&b).ranges(), &[1..=3, 12..=14, 21..=25]);
+        assert_eq!(b.difference(&a).ranges(), &[6..=9, 16..=19, 30..=31]);
+        assert_eq!(a.difference(&a), IntervalSet::new());
+        assert_eq!(a.difference(&IntervalSet::new()), a);
+
+        let mut c = IntervalSet::new();
+        c.insert(3..=4);
+        c.insert(0..=1);
+        c.insert(2..=2);
+        assert_eq!(c.ranges(), &[0..=4]);
+    }
+}
@@ -0,0 +1,121 @@
+use std::ops::RangeInclusive;
+
+use anyhow::{Context, Result};
+pub mod camp;
+pub mod groups;
+pub mod interval_set;
+pub mod reassign;
+pub mod sweep;
+
+pub use interval_set::IntervalSet;
+
+/// The section assignments of a group of elves, one per elf
+pub type Group = Vec<RangeInclusive<u32>>;
+
+/// Written in place of a range for an elf with no sections to clean
+pub const IDLE: &str = "-";
+
+/// Parses one group per line, as `start-end` assignments separated by commas. An elf with no
+/// sections is written as [`IDLE`] and gets an empty range.
+pub fn parse_assignments(section_assignments: &str) -> Result<Vec<Group>> {
+    section_assignments
+        .lines()
+        .enumerate()
+        .map(|(i, group)| {
+            group
+                .split(',')
+                .map(|assignment| match assignment {
+                    IDLE => Ok(RangeInclusive::new(1, 0)),
+                    _ => parse_range(assignment),
+                })
+                .collect::<Result<Group>>()
+                .with_context(|| format!("Invalid assignment group on line {}", i + 1))
+        })
+        .collect()
+}
+
+/// Every assignment with its line in the input, starting at 1
+pub fn numbered_assignments(
+    groups: &[Group],
+) -> impl Iterator<Item = (usize, &RangeInclusive<u32>)> {
+    (1..).zip(groups).flat_map(|(line, group)| group.iter().map(move |range| (line, range)))
+}
+
+/// Parses a set of sections written as `start-end` ranges separated by commas
+pub fn parse_sections(sections: &str) -> Result<IntervalSet> {
+    sections.split(',').map(parse_range).collect()
+}
+
+fn parse_range(assignment: &str) -> Result<RangeInclusive<u32>> {
+    let (start, end) = assignment
+        .split_once('-')
+        .with_context(|| format!("Expected a section range like 2-4, got {assignment:?}"))?;
+    let start = start.parse().with_context(|| format!("Invalid section {start:?}"))?;
+    let end = end.parse().with_context(|| format!("Invalid section {end:?}"))?;
+    Ok(start..=end)
+}
+
+/// Counts the groups where one elf's range fully contains another's, and the groups where any
+/// two ranges overlap at all. For pairs of elves these are the puzzle's two answers.
+pub fn solve(section_assignments: &str) -> Result<(u32, u32)> {
+    let mut fully_overlapping_groups = 0;
+    let mut overlapping_groups = 0;
+    for report in groups::reports(&parse_assignments(section_assignments)?) {
+        if report.any_containment() {
+            fully_overlapping_groups += 1;
+        }
+
+        if report.any_overlap() {
+            overlapping_groups += 1;
+        }
+    }
+
+    Ok((fully_overlapping_groups, overlapping_groups))
+}
+
+pub(crate) fn fully_overlapping(x: &RangeInclusive<u32>, y: &RangeInclusive<u32>) -> bool {
+    if x.is_empty() || y.is_empty() {
+        return false;
+    }
+    (x.start() <= y.start() && x.end() >= y.end()) || (y.start() <= x.start() && y.end() >= x.end())
+}
+
+pub(crate) fn overlapping(x: &RangeInclusive<u32>, y: &RangeInclusive<u32>) -> bool {
+    !x.is_empty() && !y.is_empty() && x.start() <= y.end() && y.start() <= x.end()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(solve(include_str!("../example.txt")).unwrap(), (2, 4));
+        assert_eq!(solve("1-2,3-4,2-3\n1-9,5-5,7-8\n1-1,2-2,3-3").unwrap(), (1, 2));
+    }
+
+    #[test]
+    fn test_parse_assignments() {
+        let pairs = parse_assignments(include_str!("../example.txt")).unwrap();
+        assert_eq!(pairs.len(), 6);
+        assert_eq!(pairs[0], vec![2..=4, 6..=8]);
+
+        let groups = parse_assignments("2-4\n2-4,6-8,1-1").unwrap();
+        assert_eq!(groups, vec![vec![2..=4], vec![2..=4, 6..=8, 1..=1]]);
+
+        let e = parse_assignments("2-4,6-8\n2-x,6-8").unwrap_err();
+        assert_eq!(
+            format!("{e:#}"),
+            "Invalid assignment group on line 2: Invalid section \"x\": invalid digit found in string"
+        );
+        assert!(parse_assignments("2-4,").is_err());
+        let groups = parse_assignments("-,2-4").unwrap();
+        assert!(groups[0][0].is_empty());
+        assert_eq!(groups[0][1], 2..=4);
+        assert_eq!(solve("-,2-4\n-,-").unwrap(), (0, 0));
+
+        assert_eq!(parse_sections("5-9,1-3,4-4").unwrap().ranges(), &[1..=9]);
+        assert!(parse_sections("5").is_err());
+        assert!(parse_assignments("24,6-8").is_err());
+    }
+}
@@ -1,50 +1,59 @@
 use std::env;
 use std::fs;
-use std::ops::RangeInclusive;
 
-use itertools::Itertools;
+use anyhow::{Context, Result};
+use day4_camp_cleanup::camp::Coverage;
+use day4_camp_cleanup::groups::{reports, table};
+use day4_camp_cleanup::reassign::reassign;
+use day4_camp_cleanup::sweep::overlaps;
+use day4_camp_cleanup::{numbered_assignments, parse_assignments, parse_sections, solve};
 
-fn main() {
+fn main() -> Result<()> {
     let args: Vec<String> = env::args().collect();
     let file_path = &args[1];
 
     let section_assignments =
         fs::read_to_string(file_path).expect("Should have been able to read the file");
+    let option = |name: &str| {
+        let idx = args.iter().position(|arg| arg == name)?;
+        args.get(idx + 1).map(String::as_str)
+    };
 
-    let mut fully_overlapping_pairs = 0;
-    let mut overlapping_pairs = 0;
-    for elf_pair in section_assignments.lines() {
-        let (elf_1, elf_2) = elf_pair
-            .split(',')
-            .map(|assignment| {
-                let (start, end) = assignment
-                    .split('-')
-                    .map(|section| section.parse::<u32>().unwrap())
-                    .collect_tuple()
-                    .unwrap();
-
-                start..=end
-            })
-            .collect_tuple()
-            .unwrap();
-
-        if fully_overlapping(&elf_1, &elf_2) {
-            fully_overlapping_pairs += 1;
-        }
-
-        if overlapping(&elf_1, &elf_2) {
-            overlapping_pairs += 1;
-        }
+    let (fully_overlapping_groups, overlapping_groups) = solve(&section_assignments)?;
+
+    println!("Number of assignment groups where one range fully contains another: {fully_overlapping_groups}");
+    println!("Number of assignment groups where one range at least partially contains another: {overlapping_groups}");
+
+    let groups = parse_assignments(&section_assignments)?;
+
+    // `--groups` reports how the assignments within each line overlap
+    if args.iter().any(|arg| arg == "--groups") {
+        print!("{}", table(&reports(&groups)));
     }
 
-    println!("Number of assignment pairs where one range fully contains the other: {fully_overlapping_pairs}");
-    println!("Number of assignment pairs where one range at least partially contains the other: {overlapping_pairs}");
-}
+    // `--camp` looks at every assignment at once rather than line by line
+    if args.iter().any(|arg| arg == "--camp") {
+        println!("{}", Coverage::new(groups.iter().flatten()));
+    }
 
-fn fully_overlapping(x: &RangeInclusive<u32>, y: &RangeInclusive<u32>) -> bool {
-    (x.start() <= y.start() && x.end() >= y.end()) || (y.start() <= x.start() && y.end() >= x.end())
-}
+    // `--sweep` finds where the most elves pile up and which lines overlap each other
+    if args.iter().any(|arg| arg == "--sweep") {
+        println!("{}", overlaps(numbered_assignments(&groups)));
+    }
+
+    // `--reassign FILE` writes new assignments to FILE that clean every section exactly once,
+    // either the sections listed by `--clean 1-5,8-9` or every section someone was assigned
+    if let Some(reassigned_path) = option("--reassign") {
+        let to_clean = match option("--clean") {
+            Some(sections) => parse_sections(sections)?,
+            None => Coverage::new(groups.iter().flatten()).covered,
+        };
+        let reassignment = reassign(&groups, &to_clean)?;
+        println!("Sections handed to a different elf: {}", reassignment.new_sections);
+        println!("Elves left with nothing to clean: {}", reassignment.idle_elves());
+        fs::write(reassigned_path, reassignment.format())
+            .with_context(|| format!("Could not write {reassigned_path}"))?;
+    }
 
-fn overlapping(x: &RangeInclusive<u32>, y: &RangeInclusive<u32>) -> bool {
-    x.start() <= y.end() && y.start() <= x.end()
+    Ok(())
 }
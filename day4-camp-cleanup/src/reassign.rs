@@ -0,0 +1,206 @@
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+use anyhow::{bail, Result};
+use itertools::Itertools;
+
+use crate::{Group, IntervalSet, IDLE};
+
+/// New assignments for every elf, covering each section to be cleaned exactly once
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reassignment {
+    /// Each line's elves in their original order, with `None` for an elf with nothing to do
+    pub groups: Vec<Vec<Option<RangeInclusive<u32>>>>,
+    /// Sections handed to an elf who wasn't assigned them before
+    pub new_sections: u64,
+}
+
+impl Reassignment {
+    /// Elves left with nothing to clean
+    pub fn idle_elves(&self) -> usize {
+        self.groups.iter().flatten().filter(|range| range.is_none()).count()
+    }
+
+    /// Writes the new assignments in the input format, one line per group with every elf where
+    /// they were, and [`IDLE`] for an elf with nothing to clean
+    pub fn format(&self) -> String {
+        self.groups
+            .iter()
+            .map(|group| {
+                group
+                    .iter()
+                    .map(|range| match range {
+                        Some(range) => format!("{}-{}", range.start(), range.end()),
+                        None => IDLE.to_string(),
+                    })
+                    .join(",")
+                    + "\n"
+            })
+            .join("")
+    }
+}
+
+/// Shares out the sections in `to_clean` so each is cleaned by exactly one elf, who only gets a
+/// single range. Each stretch of sections is covered by as few elves as possible, keeping only
+/// what they were already assigned. Sections nobody was assigned go to the elf next to them in
+/// the stretch, or to an idle elf if nobody in the stretch is left, and it's an error if there's
+/// no such elf. Idle elves whose own sections are still to be cleaned in a later stretch are
+/// only picked when there's nobody else.
+pub fn reassign(groups: &[Group], to_clean: &IntervalSet) -> Result<Reassignment> {
+    let elves = groups.iter().flatten().collect::<Vec<&RangeInclusive<u32>>>();
+    let mut by_start = (0..elves.len()).filter(|&elf| !elves[elf].is_empty()).collect::<Vec<_>>();
+    by_start.sort_by_key(|&elf| *elves[elf].start());
+
+    let mut pieces = vec![None; elves.len()];
+    let mut busy = HashSet::new();
+    let mut new_sections = 0;
+    for (i, stretch) in to_clean.ranges().iter().enumerate() {
+        let (first, last) = (*stretch.start(), *stretch.end());
+        let candidates = by_start
+            .iter()
+            .copied()
+            .filter(|elf| !busy.contains(elf) && *elves[*elf].end() >= first)
+            .filter(|&elf| *elves[elf].start() <= last)
+            .collect::<Vec<usize>>();
+
+        // greedily take the elf reaching furthest past the next section to clean
+        let mut covered = Vec::<(usize, u32, u32)>::new();
+        let mut section = first as u64;
+        let mut gap_start = None;
+        let mut next = 0;
+        let mut reaching = None::<usize>;
+        while section <= last as u64 {
+            while let Some(&elf) = candidates.get(next) {
+                if *elves[elf].start() as u64 > section {
+                    break;
+                }
+                if reaching.is_none_or(|best| elves[elf].end() > elves[best].end()) {
+                    reaching = Some(elf);
+                }
+                next += 1;
+            }
+
+            match reaching.take().filter(|&elf| *elves[elf].end() as u64 >= section) {
+                Some(elf) => {
+                    let end = (*elves[elf].end()).min(last);
+                    let start = gap_start.take().unwrap_or(section as u32);
+                    covered.push((elf, start, end));
+                    section = end as u64 + 1;
+                }
+                None => {
+                    // nobody has the next sections, so the elf before them or after them does
+                    let gap_end = candidates
+                        .get(next)
+                        .map_or(last, |&elf| elves[elf].start() - 1)
+                        .min(last);
+                    new_sections += (gap_end as u64) - section + 1;
+                    match covered.last_mut() {
+                        Some((_, _, end)) if gap_start.is_none() => *end = gap_end,
+                        _ => {
+                            gap_start.get_or_insert(section as u32);
+                        }
+                    }
+                    section = gap_end as u64 + 1;
+                }
+            }
+        }
+
+        if let Some(start) = gap_start {
+            // nobody was assigned anything in the rest of the stretch, so take an idle elf,
+            // keeping back the ones a later stretch could use
+            let later = IntervalSet::merge(to_clean.ranges()[i + 1..].iter().cloned());
+            let needed_later = |elf: usize| {
+                !IntervalSet::merge([elves[elf].clone()]).intersection(&later).is_empty()
+            };
+            let Some(elf) = (0..elves.len())
+                .filter(|elf| {
+                    !busy.contains(elf) && covered.iter().all(|&(other, _, _)| other != *elf)
+                })
+                .min_by_key(|&elf| needed_later(elf))
+            else {
+                bail!("No elf is left to clean sections {start}-{last}");
+            };
+            covered.push((elf, start, last));
+        }
+
+        for (elf, start, end) in covered {
+            busy.insert(elf);
+            pieces[elf] = Some(start..=end);
+        }
+    }
+
+    let mut pieces = pieces.into_iter();
+    let groups = groups
+        .iter()
+        .map(|group| pieces.by_ref().take(group.len()).collect())
+        .collect();
+    Ok(Reassignment { groups, new_sections })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::camp::Coverage;
+    use crate::parse_assignments;
+
+    #[test]
+    fn test_reassign_example() {
+        let groups = parse_assignments(include_str!("../example.txt")).unwrap();
+        let to_clean = Coverage::new(groups.iter().flatten()).covered;
+
+        let reassignment = reassign(&groups, &to_clean).unwrap();
+        // 2-8 on line 4 reaches furthest, leaving 7-9 on line 3 to finish off
+        assert_eq!(reassignment.format(), "-,-\n-,-\n-,9-9\n2-8,-\n-,-\n-,-\n");
+        assert_eq!(reassignment.new_sections, 0);
+        assert_eq!(reassignment.idle_elves(), 10);
+
+        let reassigned = parse_assignments(&reassignment.format()).unwrap();
+        assert_eq!(reassigned.len(), groups.len());
+        assert!(reassigned.iter().zip(&groups).all(|(new, old)| new.len() == old.len()));
+        assert_eq!(reassigned[2][1], 9..=9);
+        assert_eq!(reassigned[3][0], 2..=8);
+        assert!(reassigned[0].iter().all(|range| range.is_empty()));
+        let coverage = Coverage::new(reassigned.iter().flatten());
+        assert_eq!(coverage.covered, to_clean);
+        assert!(coverage.shared.is_empty());
+    }
+
+    #[test]
+    fn test_reassign_gaps() {
+        let groups = parse_assignments("1-3,6-8\n2-4,12-15\n20-30").unwrap();
+        let to_clean = IntervalSet::merge([1..=10, 13..=14, 17..=18]);
+
+        let reassignment = reassign(&groups, &to_clean).unwrap();
+        assert_eq!(
+            reassignment.groups,
+            vec![
+                vec![Some(1..=3), Some(6..=10)],
+                vec![Some(4..=5), Some(13..=14)],
+                vec![Some(17..=18)],
+            ]
+        );
+        assert_eq!(reassignment.new_sections, 1 + 2 + 2);
+        assert_eq!(reassignment.format(), "1-3,6-10\n4-5,13-14\n17-18\n");
+
+        // a stretch before anyone's assignment goes to the first elf after it
+        let reassignment = reassign(&groups, &IntervalSet::merge([0..=2])).unwrap();
+        assert_eq!(reassignment.format(), "0-2,-\n-,-\n-\n");
+
+        // every elf is busy by the time section 40 comes around
+        let to_clean = IntervalSet::merge([1..=3, 5..=5, 7..=7, 9..=9, 11..=11, 40..=40]);
+        let e = reassign(&groups, &to_clean).unwrap_err();
+        assert_eq!(e.to_string(), "No elf is left to clean sections 40-40");
+    }
+
+    #[test]
+    fn test_reassign_keeps_idle_elves_for_later_stretches() {
+        // nobody has 5-6, and taking the first idle elf for it would leave nobody for 20-21
+        let groups = parse_assignments("10-12,20-21\n30-31").unwrap();
+        let to_clean = IntervalSet::merge([5..=6, 10..=11, 20..=21]);
+
+        let reassignment = reassign(&groups, &to_clean).unwrap();
+        assert_eq!(reassignment.format(), "10-11,20-21\n5-6\n");
+        assert_eq!(reassignment.new_sections, 2);
+        assert_eq!(reassignment.idle_elves(), 0);
+    }
+}
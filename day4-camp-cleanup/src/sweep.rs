@@ -0,0 +1,132 @@
+use std::collections::BTreeSet;
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use itertools::Itertools;
+
+use crate::IntervalSet;
+
+/// Where the elves' assignments pile up, across every line of the file
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Overlaps {
+    /// The most elves assigned to any one section
+    pub max_elves: usize,
+    /// The sections assigned to `max_elves` elves
+    pub busiest: IntervalSet,
+    /// Every pair of lines, starting at 1, with an assignment on one overlapping an assignment
+    /// on the other, lowest line first
+    pub line_pairs: Vec<(usize, usize)>,
+}
+
+/// Sweeps across the sections once, taking each assignment with the line it's on. Takes
+/// O(n log n) time for n assignments, plus the time to list the overlapping lines.
+pub fn overlaps<'a>(
+    assignments: impl IntoIterator<Item = (usize, &'a RangeInclusive<u32>)>,
+) -> Overlaps {
+    let mut assignments = assignments
+        .into_iter()
+        .filter(|(_, range)| !range.is_empty())
+        .map(|(line, range)| (*range.start(), *range.end(), line))
+        .collect::<Vec<(u32, u32, usize)>>();
+    assignments.sort_unstable();
+
+    // the assignments that reach the section being swept, by where they end
+    let mut active = BTreeSet::<(u32, usize)>::new();
+    let mut line_pairs = BTreeSet::new();
+    for (i, &(start, end, line)) in assignments.iter().enumerate() {
+        while let Some(&(active_end, _)) = active.first() {
+            if active_end >= start {
+                break;
+            }
+            active.pop_first();
+        }
+        for &(_, other) in &active {
+            let other_line = assignments[other].2;
+            if other_line != line {
+                line_pairs.insert((other_line.min(line), other_line.max(line)));
+            }
+        }
+        active.insert((end, i));
+    }
+
+    // each assignment adds an elf at its start and takes one away just past its end
+    let events = assignments
+        .iter()
+        .flat_map(|&(start, end, _)| [(start as u64, 1), (end as u64 + 1, -1)])
+        .sorted_unstable()
+        .group_by(|&(section, _)| section)
+        .into_iter()
+        .map(|(section, changes)| (section, changes.map(|(_, change)| change).sum::<i64>()))
+        .collect::<Vec<(u64, i64)>>();
+
+    let mut elves = 0;
+    let mut depths = Vec::with_capacity(events.len());
+    for &(section, change) in &events {
+        elves += change;
+        depths.push((section, elves as usize));
+    }
+    let max_elves = depths.iter().map(|&(_, elves)| elves).max().unwrap_or(0);
+    let busiest = depths
+        .iter()
+        .tuple_windows()
+        .filter(|((_, elves), _)| max_elves > 0 && *elves == max_elves)
+        .map(|(&(start, _), &(end, _))| start as u32..=(end - 1) as u32)
+        .collect();
+
+    Overlaps {
+        max_elves,
+        busiest,
+        line_pairs: line_pairs.into_iter().collect(),
+    }
+}
+
+impl fmt::Display for Overlaps {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sections = self
+            .busiest
+            .ranges()
+            .iter()
+            .map(|range| format!("{}-{}", range.start(), range.end()))
+            .join(", ");
+        writeln!(
+            f,
+            "Most elves assigned to one section: {} (sections {sections})",
+            self.max_elves
+        )?;
+        write!(f, "Lines with overlapping assignments: {}", self.line_pairs.len())?;
+        for (line_1, line_2) in &self.line_pairs {
+            write!(f, "\n  lines {line_1} and {line_2}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{numbered_assignments, parse_assignments};
+
+    #[test]
+    fn test_example_overlaps() {
+        let groups = parse_assignments(include_str!("../example.txt")).unwrap();
+        let overlaps = overlaps(numbered_assignments(&groups));
+
+        // every assignment but 2-3, 4-5 and 7-9 takes in section 6
+        assert_eq!(overlaps.max_elves, 8);
+        assert_eq!(overlaps.busiest.ranges(), &[6..=6]);
+        assert_eq!(overlaps.line_pairs.len(), 15);
+        assert!(overlaps.to_string().starts_with(
+            "Most elves assigned to one section: 8 (sections 6-6)\nLines with overlapping assignments: 15\n  lines 1 and 2\n"
+        ));
+    }
+
+    #[test]
+    fn test_overlaps() {
+        let overlaps = overlaps([(1, &(1..=3)), (1, &(2..=4)), (2, &(5..=u32::MAX)), (3, &(4..=5))]);
+
+        assert_eq!(overlaps.max_elves, 2);
+        assert_eq!(overlaps.busiest.ranges(), &[2..=5]);
+        assert_eq!(overlaps.line_pairs, vec![(1, 3), (2, 3)]);
+        assert_eq!(super::overlaps([]), Overlaps::default());
+    }
+}
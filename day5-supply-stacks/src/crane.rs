@@ -0,0 +1,250 @@
+use anyhow::{bail, Context, Result};
+
+use crate::{validate, Rearrangement, Stacks};
+
+/// A model of crane, deciding the order crates land in when they're moved between stacks
+pub trait Crane {
+    /// Carries out one step of the rearrangement procedure, leaving the stacks alone if the step
+    /// names a stack that doesn't exist or moves more crates than there are
+    fn rearrange(&mut self, stacks: &mut Stacks, rearrangement: &Rearrangement) -> Result<()>;
+}
+
+/// Moves the crates in one go, copying them across without allocating, keeping their order or
+/// turning them upside down if `reversed`
+fn move_crates(stacks: &mut Stacks, rearrangement: &Rearrangement, reversed: bool) -> Result<()> {
+    validate(stacks, rearrangement)?;
+    let Rearrangement { num_crates_to_move, origin, dest } = *rearrangement;
+    let (origin, dest) = (origin as usize - 1, dest as usize - 1);
+    let count = num_crates_to_move as usize;
+    if origin == dest {
+        // putting crates back where they were taken from leaves the stack as it was
+        return Ok(());
+    }
+
+    let (from, to) = if origin < dest {
+        let (low, high) = stacks.split_at_mut(dest);
+        (&mut low[origin], &mut high[0])
+    } else {
+        let (low, high) = stacks.split_at_mut(origin);
+        (&mut high[0], &mut low[dest])
+    };
+    let start = from.len() - count;
+    if reversed {
+        to.extend(from[start..].iter().rev());
+    } else {
+        to.extend_from_slice(&from[start..]);
+    }
+    from.truncate(start);
+    Ok(())
+}
+
+/// The CrateMover 9000 moves crates one at a time, so they land in reverse order
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn rearrange(&mut self, stacks: &mut Stacks, rearrangement: &Rearrangement) -> Result<()> {
+        move_crates(stacks, rearrangement, true)
+    }
+}
+
+/// The CrateMover 9001 moves all the crates at once, so they keep their order
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn rearrange(&mut self, stacks: &mut Stacks, rearrangement: &Rearrangement) -> Result<()> {
+        move_crates(stacks, rearrangement, false)
+    }
+}
+
+/// Moves several crates at once like the CrateMover 9001, but can lift no more than `capacity`
+/// at a time, so a bigger move takes several trips
+#[derive(Debug, Clone, Copy)]
+pub struct LimitedLift {
+    capacity: u32,
+}
+
+impl LimitedLift {
+    pub fn new(capacity: u32) -> Result<Self> {
+        if capacity == 0 {
+            bail!("A crane has to be able to lift at least one crate");
+        }
+        Ok(Self { capacity })
+    }
+
+    pub fn capacity(&self) -> u32 {
+        self.capacity
+    }
+}
+
+impl Crane for LimitedLift {
+    fn rearrange(&mut self, stacks: &mut Stacks, rearrangement: &Rearrangement) -> Result<()> {
+        // check the whole move first so it isn't left half done
+        validate(stacks, rearrangement)?;
+        let mut remaining = rearrangement.num_crates_to_move;
+        while remaining > 0 {
+            let trip = remaining.min(self.capacity);
+            let trip_rearrangement = Rearrangement { num_crates_to_move: trip, ..*rearrangement };
+            move_crates(stacks, &trip_rearrangement, false)?;
+            remaining -= trip;
+        }
+        Ok(())
+    }
+}
+
+/// Moves several crates at once, but flips them over on every other move, starting with the
+/// second
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Alternating {
+    moves: usize,
+}
+
+impl Crane for Alternating {
+    fn rearrange(&mut self, stacks: &mut Stacks, rearrangement: &Rearrangement) -> Result<()> {
+        move_crates(stacks, rearrangement, !self.moves.is_multiple_of(2))?;
+        self.moves += 1;
+        Ok(())
+    }
+}
+
+/// Picks a crane by model: `9000`, `9001`, `limited:CAPACITY` or `alternating`
+pub fn crane(model: &str) -> Result<Box<dyn Crane>> {
+    Ok(match model {
+        "9000" => Box::new(CrateMover9000),
+        "9001" => Box::new(CrateMover9001),
+        "alternating" => Box::new(Alternating::default()),
+        _ => {
+            let Some(capacity) = model.strip_prefix("limited:") else {
+                bail!(
+                    "Unknown crane model {model:?}, expected 9000, 9001, limited:N or alternating"
+                );
+            };
+            let capacity = capacity
+                .parse()
+                .with_context(|| format!("Invalid lift capacity {capacity:?}"))?;
+            Box::new(LimitedLift::new(capacity)?)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_input, rearrange};
+    use common::rng::Lcg;
+
+    #[test]
+    fn test_crane_models() {
+        let (stacks, procedure) = parse_input(include_str!("../example.txt")).unwrap();
+        let top = |model| {
+            rearrange(stacks.clone(), &procedure, crane(model).unwrap().as_mut()).unwrap()
+        };
+
+        assert_eq!(top("9000"), "CMZ");
+        assert_eq!(top("9001"), "MCD");
+        assert_eq!(top("limited:1"), "CMZ");
+        assert_eq!(top("limited:3"), "MCD");
+        // the 3 crate move takes two trips, leaving Z on top of stack 3 rather than N or D
+        assert_eq!(top("limited:2"), "MCZ");
+        assert_eq!(top("alternating"), "MCZ");
+
+        assert_eq!(
+            crane("").err().unwrap().to_string(),
+            "Unknown crane model \"\", expected 9000, 9001, limited:N or alternating"
+        );
+        assert!(LimitedLift::new(0).is_err());
+        assert_eq!(LimitedLift::new(4).unwrap().capacity(), 4);
+        assert!(crane("limited:0").is_err());
+        assert!(crane("limited:x").is_err());
+    }
+
+    #[test]
+    fn test_invalid_steps_leave_stacks_alone() {
+        let (stacks, _) = parse_input(include_str!("../example.txt")).unwrap();
+        let step = |num_crates_to_move, origin, dest| Rearrangement {
+            num_crates_to_move,
+            origin,
+            dest,
+        };
+
+        for crane in [&mut CrateMover9000 as &mut dyn Crane, &mut LimitedLift::new(2).unwrap()] {
+            let mut moved = stacks.clone();
+            assert!(crane.rearrange(&mut moved, &step(1, 0, 1)).is_err());
+            assert!(crane.rearrange(&mut moved, &step(1, 1, 4)).is_err());
+            assert!(crane.rearrange(&mut moved, &step(3, 1, 2)).is_err());
+            assert_eq!(moved, stacks);
+        }
+    }
+
+    /// `stacks` stacks of `height` crates and a deterministic procedure of `moves` moves of up
+    /// to 10 crates between them
+    fn generated_procedure(
+        stacks: usize,
+        height: usize,
+        moves: usize,
+    ) -> (Stacks, Vec<Rearrangement>) {
+        let mut rng = Lcg::new(0x2022);
+        let mut next = move |modulo: usize| rng.below(modulo as u64) as usize;
+
+        let start = (0..stacks)
+            .map(|_| (0..height).map(|_| b'A' + next(26) as u8).collect())
+            .collect::<Stacks>();
+        let mut heights = vec![height; stacks];
+        let procedure = (0..moves)
+            .map(|_| {
+                let origin = (0..).map(|_| next(stacks)).find(|&s| heights[s] > 0).unwrap();
+                let dest = next(stacks);
+                let count = 1 + next(heights[origin].min(10));
+                heights[origin] -= count;
+                heights[dest] += count;
+                Rearrangement {
+                    num_crates_to_move: count as u32,
+                    origin: origin as u32 + 1,
+                    dest: dest as u32 + 1,
+                }
+            })
+            .collect();
+        (start, procedure)
+    }
+
+    /// Checks both cranes against moving crates one by one through a spare stack, as the
+    /// procedure describes
+    fn check_against_one_crate_at_a_time(stacks: usize, height: usize, moves: usize) {
+        let (stacks, procedure) = generated_procedure(stacks, height, moves);
+
+        let mut one_by_one = [stacks.clone(), stacks.clone()];
+        for &Rearrangement { num_crates_to_move, origin, dest } in &procedure {
+            let (origin, dest) = (origin as usize - 1, dest as usize - 1);
+            let mut lifted = Vec::new();
+            for _ in 0..num_crates_to_move {
+                let moving_crate = one_by_one[0][origin].pop().unwrap();
+                one_by_one[0][dest].push(moving_crate);
+                lifted.push(one_by_one[1][origin].pop().unwrap());
+            }
+            while let Some(moving_crate) = lifted.pop() {
+                one_by_one[1][dest].push(moving_crate);
+            }
+        }
+
+        let mut single = stacks.clone();
+        let mut multiple = stacks;
+        for rearrangement in &procedure {
+            CrateMover9000.rearrange(&mut single, rearrangement).unwrap();
+            CrateMover9001.rearrange(&mut multiple, rearrangement).unwrap();
+        }
+        assert_eq!(single, one_by_one[0]);
+        assert_eq!(multiple, one_by_one[1]);
+    }
+
+    #[test]
+    fn test_matches_one_crate_at_a_time() {
+        check_against_one_crate_at_a_time(200, 20, 20_000);
+    }
+
+    #[test]
+    #[ignore = "benchmark, run with --release -- --ignored"]
+    fn bench_millions_of_moves() {
+        check_against_one_crate_at_a_time(2000, 20, 2_000_000);
+    }
+}
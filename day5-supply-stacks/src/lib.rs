@@ -0,0 +1,262 @@
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Result};
+
+pub mod crane;
+pub mod render;
+
+pub use crane::{Crane, CrateMover9000, CrateMover9001};
+
+/// A crate, by the letter it's marked with
+type Crate = u8;
+
+/// A stack's crates from the bottom up
+type Stack = Vec<Crate>;
+
+/// The stacks in order, so stack 1 comes first
+pub type Stacks = Vec<Stack>;
+
+/// One step of the rearrangement procedure, e.g. `move 1 from 2 to 1`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rearrangement {
+    pub num_crates_to_move: u32,
+    pub origin: u32,
+    pub dest: u32,
+}
+
+impl FromStr for Rearrangement {
+    type Err = anyhow::Error;
+
+    fn from_str(line: &str) -> Result<Self> {
+        let rearrangement: Vec<&str> = line.split_whitespace().collect();
+        let ["move", num_crates_to_move, "from", origin, "to", dest] = rearrangement[..] else {
+            bail!("Unexpected rearrangement {line:?}");
+        };
+
+        let parse = |val: &str| {
+            val.parse::<u32>()
+                .with_context(|| format!("Invalid number {val:?} in rearrangement {line:?}"))
+        };
+
+        Ok(Self {
+            num_crates_to_move: parse(num_crates_to_move)?,
+            origin: parse(origin)?,
+            dest: parse(dest)?,
+        })
+    }
+}
+
+impl fmt::Display for Rearrangement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "move {} from {} to {}", self.num_crates_to_move, self.origin, self.dest)
+    }
+}
+
+/// Parses both the stacks drawing and the rearrangement procedure that follows it.
+pub fn parse_input(input: &str) -> Result<(Stacks, Vec<Rearrangement>)> {
+    Ok((parse_stacks(input)?, parse_rearrangement_procedure(input)?))
+}
+
+/// Parses the drawing of the starting stacks, everything up to the first empty line.
+pub fn parse_stacks(input: &str) -> Result<Stacks> {
+    let mut drawing = input
+        .lines()
+        .take_while(|line| !line.is_empty())
+        .collect::<Vec<&str>>();
+    let stack_ids = drawing.pop().context("Missing the line of stack numbers")?;
+
+    let mut stacks = Stacks::new();
+    for (id, expected_id) in stack_ids.split_whitespace().zip(1u32..) {
+        let id = id
+            .parse::<u32>()
+            .with_context(|| format!("Invalid stack number {id:?}"))?;
+        if id != expected_id {
+            bail!("Expected stack number {expected_id} but found {id}");
+        }
+        stacks.push(Stack::new());
+    }
+
+    // build stacks
+    for line in drawing.iter().rev() {
+        for (crate_column, i) in line.as_bytes().chunks(4).zip(1u32..) {
+            match crate_column {
+                [b'[', label, b']', rest @ ..]
+                    if label.is_ascii_graphic() && rest.trim_ascii().is_empty() =>
+                {
+                    stacks
+                        .get_mut(i as usize - 1)
+                        .with_context(|| format!("Crate in column {i} has no stack: {line:?}"))?
+                        .push(*label);
+                }
+                _ if crate_column.trim_ascii().is_empty() => {}
+                _ => bail!("Expected a crate like [A] in column {i}: {line:?}"),
+            }
+        }
+    }
+
+    Ok(stacks)
+}
+
+/// Parses the rearrangement procedure, everything after the first empty line. Errors give the
+/// line in the whole input, counting the drawing and the empty line before the procedure.
+pub fn parse_rearrangement_procedure(input: &str) -> Result<Vec<Rearrangement>> {
+    input
+        .lines()
+        .enumerate()
+        .skip_while(|(_, line)| !line.is_empty())
+        .skip(1)
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| line.parse().with_context(|| format!("Line {} of the input", i + 1)))
+        .collect()
+}
+
+/// Checks that a step of the procedure can be carried out: both stacks exist, and the origin has
+/// as many crates as are to be moved.
+pub fn validate(stacks: &Stacks, rearrangement: &Rearrangement) -> Result<()> {
+    for id in [rearrangement.origin, rearrangement.dest] {
+        if id == 0 || id as usize > stacks.len() {
+            bail!("Stack {id} doesn't exist, the stacks are numbered 1 to {}", stacks.len());
+        }
+    }
+
+    let available = stacks[rearrangement.origin as usize - 1].len();
+    let requested = rearrangement.num_crates_to_move as usize;
+    if requested > available {
+        bail!(
+            "Stack {} has {available} crates but {requested} were requested",
+            rearrangement.origin
+        );
+    }
+    Ok(())
+}
+
+/// Carries out the procedure with `crane`, calling `after_each` with every step and the stacks
+/// it leaves behind. Stops at the first step that can't be carried out.
+pub fn carry_out(
+    stacks: &mut Stacks,
+    rearrangement_procedure: &[Rearrangement],
+    crane: &mut dyn Crane,
+    mut after_each: impl FnMut(&Rearrangement, &Stacks),
+) -> Result<()> {
+    for (i, rearrangement) in rearrangement_procedure.iter().enumerate() {
+        crane.rearrange(stacks, rearrangement).with_context(|| {
+            format!("Step {} of the rearrangement procedure, {rearrangement}", i + 1)
+        })?;
+        after_each(rearrangement, stacks);
+    }
+    Ok(())
+}
+
+/// The crates on top of each stack once the procedure has been carried out, moving crates one
+/// at a time for part 1 and all at once for part 2.
+pub fn crates_on_top_of_each_stack(
+    stacks: Stacks,
+    rearrangement_procedure: &[Rearrangement],
+) -> Result<(String, String)> {
+    Ok((
+        rearrange(stacks.clone(), rearrangement_procedure, &mut CrateMover9000)?,
+        rearrange(stacks, rearrangement_procedure, &mut CrateMover9001)?,
+    ))
+}
+
+/// Carries out the procedure with `crane`, returning the crates that end up on top of each stack
+pub fn rearrange(
+    mut stacks: Stacks,
+    rearrangement_procedure: &[Rearrangement],
+    crane: &mut dyn Crane,
+) -> Result<String> {
+    carry_out(&mut stacks, rearrangement_procedure, crane, |_, _| {})?;
+    Ok(top_of_stack_string(stacks))
+}
+
+fn top_of_stack_string(stacks: Stacks) -> String {
+    stacks.iter().filter_map(|stack| stack.last()).map(|&label| label as char).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crates_on_top_of_each_stack() {
+        let input = include_str!("../example.txt");
+
+        let (stacks, rearrangement_procedure) = parse_input(input).unwrap();
+        let (part1, part2) = crates_on_top_of_each_stack(stacks, &rearrangement_procedure).unwrap();
+        assert_eq!(part1, "CMZ");
+        assert_eq!(part2, "MCD");
+    }
+
+    #[test]
+    fn test_parse_input_errors() {
+        assert!(parse_input("[A]\n 1   3\n\nmove 1 from 1 to 3").is_err());
+        assert!(parse_input("        [A]\n 1   2\n\nmove 1 from 1 to 2").is_err());
+        assert!(parse_input("[A] [B]\n 1   2\n\nmove one from 1 to 2").is_err());
+        assert!(parse_input("[A] {B}\n 1   2\n\nmove 1 from 1 to 2").is_err());
+        assert!(parse_input("[é]\n 1\n\nmove 1 from 1 to 1").is_err());
+    }
+
+    #[test]
+    fn test_invalid_rearrangements() {
+        let (stacks, _) = parse_input(include_str!("../example.txt")).unwrap();
+        let run = |procedure: &str| {
+            let procedure = parse_rearrangement_procedure(&format!("\n{procedure}"))?;
+            crates_on_top_of_each_stack(stacks.clone(), &procedure)
+        };
+
+        let e = run("move 1 from 2 to 1\nmove 4 from 1 to 3").unwrap_err();
+        assert_eq!(
+            format!("{e:#}"),
+            "Step 2 of the rearrangement procedure, move 4 from 1 to 3: Stack 1 has 3 crates but 4 were requested"
+        );
+        let e = run("move 1 from 4 to 1").unwrap_err();
+        assert_eq!(
+            format!("{e:#}"),
+            "Step 1 of the rearrangement procedure, move 1 from 4 to 1: Stack 4 doesn't exist, the stacks are numbered 1 to 3"
+        );
+        assert!(run("move 1 from 1 to 0").is_err());
+        assert!(run("move 1 from 3 to 1\nmove 1 from 3 to 2").is_err());
+        let e = run("move 1 from 3 to 1\nmove x from 3 to 2").unwrap_err();
+        assert!(format!("{e:#}").starts_with("Line 3 of the input: "));
+        let moved = run("move 0 from 3 to 1\nmove 3 from 2 to 2").unwrap();
+        assert_eq!(moved, ("NDP".to_string(), "NDP".to_string()));
+    }
+
+    #[test]
+    fn test_procedure_errors_name_the_input_line() {
+        // the drawing takes lines 1 to 4 and the procedure starts after the empty line 5
+        let input = include_str!("../example.txt")
+            .replace("move 2 from 2 to 1", "move 2 from 2 to");
+        let e = parse_input(&input).unwrap_err();
+        assert_eq!(
+            format!("{e:#}"),
+            "Line 8 of the input: Unexpected rearrangement \"move 2 from 2 to\""
+        );
+
+        let e = parse_input("[A]\n 1\n\nmove 1 from 1 to 1\n\nmove one from 1 to 1").unwrap_err();
+        assert!(format!("{e:#}").starts_with("Line 6 of the input: "));
+    }
+
+    #[test]
+    fn test_fuzz_target_inputs() {
+        // The fuzz target feeds whatever parses straight into the crane, so none of these may panic
+        let inputs = [" 1\n", " 1", " 1\n\nmove 1 from 1 to 1", "\n 1   2\n\nmove 1 from 1 to 2"];
+        for input in inputs {
+            if let Ok((stacks, procedure)) = parse_input(input) {
+                let _ = crates_on_top_of_each_stack(stacks, &procedure);
+            }
+        }
+        let (stacks, procedure) = parse_input(" 1   2\n\nmove 1 from 1 to 2").unwrap();
+        assert!(crates_on_top_of_each_stack(stacks, &procedure).is_err());
+        let (stacks, procedure) = parse_input(" 1\n").unwrap();
+        let tops = crates_on_top_of_each_stack(stacks, &procedure).unwrap();
+        assert_eq!(tops, (String::new(), String::new()));
+    }
+
+    #[test]
+    fn test_rearrangement_round_trip() {
+        let rearrangement = "move 12 from 3 to 1".parse::<Rearrangement>().unwrap();
+        assert_eq!(rearrangement.to_string(), "move 12 from 3 to 1");
+    }
+}
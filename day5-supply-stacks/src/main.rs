@@ -1,102 +1,42 @@
-use std::collections::HashMap;
 use std::env;
 use std::fs;
 
-type Crate = String;
+use anyhow::Result;
+use day5_supply_stacks::crane::crane;
+use day5_supply_stacks::render::render;
+use day5_supply_stacks::{carry_out, crates_on_top_of_each_stack, parse_input, rearrange};
 
-// struct Crate(String);
-
-type Stack = Vec<Crate>;
-// struct Stack {
-//     crates: Vec<Crate>,
-//     id: u32
-// }
-
-fn main() {
+fn main() -> Result<()> {
     let args: Vec<String> = env::args().collect();
     let file_path = &args[1];
 
     let input = fs::read_to_string(file_path).expect("Should have been able to read the file");
 
-    // for line in input.lines() {
-    //     println!("{:?}", line);
-    // }
-
-    crates_on_top_of_each_stack(input);
-}
-
-fn crates_on_top_of_each_stack(input: String) {
-    let stacks_iter = input.lines().rev().skip_while(|&line| !line.is_empty());
-
-    let mut stacks: HashMap<u32, Stack> = stacks_iter
-        .clone()
-        .nth(1)
-        .unwrap()
-        .split_whitespace()
-        .map(|id| (id.parse::<u32>().unwrap(), Stack::new()))
-        .collect();
-
-    // build stacks
-    for line in stacks_iter.clone().skip(2) {
-        let columns = format!("{:1$}", line, stacks.len() * 4)
-            .chars()
-            .collect::<Vec<char>>();
-
-        for (crate_column, i) in columns.chunks(4).zip(1u32..) {
-            if !crate_column.iter().all(|c| c.is_whitespace()) {
-                // println!("line {line}\n column {i}\n val {}\n", crate_column.iter().collect::<String>());
-                stacks
-                    .get_mut(&(i as u32))
-                    .unwrap()
-                    .push(crate_column.iter().collect());
-            }
-        }
-    }
-
-    // dbg!(stacks);
-
-    let rearrangement_procedure = input
-        .lines()
-        .skip_while(|line| !line.is_empty())
-        .skip(1)
-        .map(|line| {
-            let rearrangement: Vec<&str> = line.split_whitespace().collect();
-            let num_crates_to_move = rearrangement[1].parse::<u32>().unwrap();
-            let origin = rearrangement[3].parse::<u32>().unwrap();
-            let dest = rearrangement[5].parse::<u32>().unwrap();
-
-            (num_crates_to_move, origin, dest)
+    let (mut stacks, rearrangement_procedure) = parse_input(&input)?;
+    let model = args
+        .iter()
+        .position(|arg| arg == "--crane")
+        .map(|idx| args.get(idx + 1).map_or("", String::as_str));
+
+    // `--visualize` draws the stacks after every step, using the `--crane` model or the 9000
+    if args.iter().any(|arg| arg == "--visualize") {
+        let mut crane = crane(model.unwrap_or("9000"))?;
+        print!("{}", render(&stacks));
+        return carry_out(&mut stacks, &rearrangement_procedure, crane.as_mut(), |step, stacks| {
+            print!("\n{step}\n\n{}", render(stacks));
         });
+    }
 
-    let mut stacks_part_2 = stacks.clone();
-    for (num_crates_to_move, origin, dest) in rearrangement_procedure {
-        let mut multiple_moving_stack = Stack::new();
-
-        for _ in 0..num_crates_to_move {
-            let moving_crate_1 = stacks.get_mut(&origin).unwrap().pop().unwrap();
-            let moving_crate_2 = stacks_part_2.get_mut(&origin).unwrap().pop().unwrap();
-            stacks.get_mut(&dest).unwrap().push(moving_crate_1.clone());
-            multiple_moving_stack.push(moving_crate_2);
-        }
-
-        for _ in 0..num_crates_to_move {
-            let moving_crate_2 = multiple_moving_stack.pop().unwrap();
-            stacks_part_2.get_mut(&dest).unwrap().push(moving_crate_2);
-        }
+    // `--crane MODEL` runs the procedure with one crane: 9000, 9001, limited:CAPACITY or
+    // alternating
+    if let Some(model) = model {
+        println!("{}", rearrange(stacks, &rearrangement_procedure, crane(model)?.as_mut())?);
+        return Ok(());
     }
 
-    println!("{}", top_of_stack_string(stacks));
-    println!("{}", top_of_stack_string(stacks_part_2));
-}
+    let (part1, part2) = crates_on_top_of_each_stack(stacks, &rearrangement_procedure)?;
+    println!("{part1}");
+    println!("{part2}");
 
-fn top_of_stack_string(mut stacks: HashMap<u32, Vec<String>>) -> String {
-    (1..stacks.len() as u32 + 1)
-        .map(|i| stacks.get_mut(&i).unwrap().pop().unwrap())
-        .map(|crate_str| {
-            crate_str
-                .chars()
-                .filter(|c| c.is_alphabetic())
-                .collect::<String>()
-        })
-        .collect()
+    Ok(())
 }
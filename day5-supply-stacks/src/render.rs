@@ -0,0 +1,57 @@
+use crate::Stacks;
+
+/// Draws the stacks the way the puzzle input does, crates like `[A]` in columns four characters
+/// apart above a line of stack numbers, with no trailing whitespace
+pub fn render(stacks: &Stacks) -> String {
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+
+    let mut drawing = String::new();
+    for level in (0..height).rev() {
+        let row = stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(&label) => format!("[{}]", label as char),
+                None => String::from("   "),
+            })
+            .collect::<Vec<String>>()
+            .join(" ");
+        drawing.push_str(row.trim_end());
+        drawing.push('\n');
+    }
+    let numbers = (1..=stacks.len())
+        .map(|id| format!("{id:^3}"))
+        .collect::<Vec<String>>()
+        .join(" ");
+    drawing.push_str(numbers.trim_end());
+    drawing.push('\n');
+    drawing
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_input, parse_stacks, Crane, CrateMover9001};
+
+    #[test]
+    fn test_render_round_trip() {
+        let input = include_str!("../example.txt");
+        let header = input
+            .lines()
+            .take_while(|line| !line.is_empty())
+            .map(|line| line.to_owned() + "\n")
+            .collect::<String>();
+
+        let stacks = parse_stacks(input).unwrap();
+        assert_eq!(render(&stacks), header);
+        assert_eq!(parse_stacks(&render(&stacks)).unwrap(), stacks);
+    }
+
+    #[test]
+    fn test_render_after_rearranging() {
+        let input = "[A]\n[B] [C]\n 1   2\n\nmove 2 from 1 to 2";
+        let (mut stacks, procedure) = parse_input(input).unwrap();
+
+        CrateMover9001.rearrange(&mut stacks, &procedure[0]).unwrap();
+        assert_eq!(render(&stacks), "    [A]\n    [B]\n    [C]\n 1   2\n");
+    }
+}
@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        let _ = day13_distress_signal::parse_packet_pairs(input);
+        let _ = day13_distress_signal::parse_packets(input);
+    }
+});
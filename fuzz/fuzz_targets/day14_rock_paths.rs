@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Only the paths are parsed, filling in the rock between their points could allocate
+// arbitrarily large structures from a few coordinates.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        let _ = day14_regolith_reservoir::parse_rock_paths(input);
+    }
+});
@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        let alphabet = day3_rucksack_reorganization::Alphabet::default();
+        let _ = day3_rucksack_reorganization::parse_rucksacks(input, &alphabet, 2);
+    }
+});
@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok((stacks, procedure)) = day5_supply_stacks::parse_input(input) {
+            let _ = day5_supply_stacks::crates_on_top_of_each_stack(stacks, &procedure);
+        }
+    }
+});